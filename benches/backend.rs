@@ -0,0 +1,70 @@
+//! Compares the tableau and the revised simplex backend on sparse models of growing size.
+//!
+//! Run with `cargo bench --bench backend`.
+
+use linprog::*;
+use std::time::Instant;
+
+/// Builds a sparse model with `rows` constraints of five variables each, every tenth constraint being `>=`.
+fn sparse_model(rows: usize, backend: Backend) -> Model {
+    let columns = 2 * rows;
+    let mut state: u64 = 42;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        (state >> 33) as usize
+    };
+    let mut model = Model::new("Sparse", Objective::Max);
+    let vars: Vec<Var> = (0..columns)
+        .map(|_| model.reg_var(1.0 + (random() % 100) as f64 / 10.0))
+        .collect();
+    for row in 0..rows {
+        let sum: Vec<Summand> = (0..5)
+            .map(|_| {
+                Summand(
+                    1.0 + (random() % 100) as f64 / 10.0,
+                    &vars[random() % columns],
+                )
+            })
+            .collect();
+        if row % 10 == 9 {
+            model.reg_constr(sum, Operator::Ge, 1.0);
+        } else {
+            model.reg_constr(sum, Operator::Le, 10.0 + (random() % 100) as f64);
+        }
+    }
+    // Bound every variable
+    for chunk in vars.chunks(5) {
+        let sum = chunk.iter().map(|var| Summand(1.0, var)).collect();
+        model.reg_constr(sum, Operator::Le, 100.0);
+    }
+    model.set_options(SolverOptions {
+        backend,
+        ..SolverOptions::default()
+    });
+    model
+}
+
+fn main() {
+    println!("{:<8}{:>30}{:>30}", "rows", "Tableau", "Revised");
+    for &rows in &[100, 200, 400, 800] {
+        print!("{:<8}", rows);
+        for &backend in &[Backend::Tableau, Backend::Revised] {
+            let mut model = sparse_model(rows, backend);
+            let start = Instant::now();
+            let status = model.solve();
+            let elapsed = start.elapsed();
+            print!(
+                "{:>30}",
+                format!(
+                    "{} {:.3} ({:.0?})",
+                    status,
+                    model.optimum().unwrap_or(f64::NAN),
+                    elapsed
+                )
+            );
+        }
+        println!();
+    }
+}
@@ -0,0 +1,158 @@
+//! Compares the simplex iterations needed by the pricing rules.
+//!
+//! Run with `cargo bench --bench pricing`.
+
+use linprog::*;
+use std::time::Instant;
+
+type Row = (Vec<f64>, Operator, f64);
+
+/// The models of the integration tests: (name, objective, objective values, constraints)
+fn models() -> Vec<(&'static str, Objective, Vec<f64>, Vec<Row>)> {
+    vec![
+        (
+            "solve_1",
+            Objective::Max,
+            vec![2.0, 1.0],
+            vec![
+                (vec![2.0, -3.0], Operator::Le, 6.0),
+                (vec![1.0, 1.0], Operator::Le, 4.0),
+            ],
+        ),
+        (
+            "solve_2",
+            Objective::Max,
+            vec![3.0, 2.0],
+            vec![
+                (vec![-1.0, 2.0], Operator::Le, 14.0),
+                (vec![1.0, -1.0], Operator::Le, 3.0),
+            ],
+        ),
+        (
+            "solve_4",
+            Objective::Min,
+            vec![6.0, 3.0],
+            vec![
+                (vec![1.0, 1.0], Operator::Ge, 1.0),
+                (vec![2.0, -1.0], Operator::Ge, 1.0),
+                (vec![0.0, 3.0], Operator::Le, 2.0),
+            ],
+        ),
+        (
+            "solve_5",
+            Objective::Max,
+            vec![0.0, 1.0],
+            vec![
+                (vec![-1.0, -1.0], Operator::Le, -1.0),
+                (vec![2.0, 3.0], Operator::Le, 6.0),
+            ],
+        ),
+        (
+            "readme_example",
+            Objective::Max,
+            vec![3.0, 5.0],
+            vec![
+                (vec![1.0, 2.0], Operator::Le, 170.0),
+                (vec![1.0, 1.0], Operator::Le, 150.0),
+                (vec![0.0, 3.0], Operator::Le, 180.0),
+            ],
+        ),
+        (
+            "readme_example_story",
+            Objective::Max,
+            vec![50.0, 100.0, 110.0],
+            vec![
+                (vec![10.0, 5.0, 6.0], Operator::Le, 2500.0),
+                (vec![4.0, 10.0, 9.0], Operator::Le, 2000.0),
+                (vec![1.0, 1.5, 3.0], Operator::Le, 450.0),
+            ],
+        ),
+        (
+            "solve_degenerate",
+            Objective::Max,
+            vec![0.75, -20.0, 0.5, -6.0],
+            vec![
+                (vec![0.25, -8.0, -1.0, 9.0], Operator::Le, 0.0),
+                (vec![0.5, -12.0, -0.5, 3.0], Operator::Le, 0.0),
+                (vec![0.0, 0.0, 1.0, 0.0], Operator::Le, 1.0),
+            ],
+        ),
+        random_model(40, 60),
+    ]
+}
+
+/// A dense, feasible and bounded model with pseudo random coefficients.
+fn random_model(rows: usize, columns: usize) -> (&'static str, Objective, Vec<f64>, Vec<Row>) {
+    let mut state: u64 = 42;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        ((state >> 33) % 1000) as f64 / 100.0 + 1.0
+    };
+    let objective_values = (0..columns).map(|_| random()).collect();
+    let constraints = (0..rows)
+        .map(|_| {
+            let a = (0..columns).map(|_| random()).collect();
+            (a, Operator::Le, 100.0 * random())
+        })
+        .collect();
+    (
+        "random 40x60",
+        Objective::Max,
+        objective_values,
+        constraints,
+    )
+}
+
+fn main() {
+    let rules = [
+        ("Dantzig", Pricing::Dantzig),
+        ("SteepestEdge", Pricing::SteepestEdge),
+        ("Devex", Pricing::Devex),
+        ("Partial(8)", Pricing::Partial(8)),
+    ];
+    print!("{:<24}", "model");
+    for (name, _) in &rules {
+        print!("{:>20}", name);
+    }
+    println!();
+    for (name, objective, objective_values, constraints) in models() {
+        print!("{:<24}", name);
+        for &(_, pricing) in &rules {
+            let objective = match &objective {
+                Objective::Max => Objective::Max,
+                Objective::Min => Objective::Min,
+            };
+            let mut model = Model::new(name, objective);
+            let vars: Vec<Var> = objective_values
+                .iter()
+                .map(|&value| model.reg_var(value))
+                .collect();
+            for (a, op, b) in &constraints {
+                let op = match op {
+                    Operator::Ge => Operator::Ge,
+                    Operator::E => Operator::E,
+                    Operator::Le => Operator::Le,
+                };
+                let sum = a
+                    .iter()
+                    .zip(&vars)
+                    .map(|(&factor, var)| Summand(factor, var))
+                    .collect();
+                model.reg_constr(sum, op, *b);
+            }
+            let start = Instant::now();
+            model.optimize_with(SolverOptions {
+                pricing,
+                ..SolverOptions::default()
+            });
+            let elapsed = start.elapsed();
+            print!(
+                "{:>20}",
+                format!("{} ({:.0?})", model.iteration_count(), elapsed)
+            );
+        }
+        println!();
+    }
+}
@@ -0,0 +1,148 @@
+//! The serialized form of a [`Model`](struct.model.html): Its variables, constraints, objective and options.
+//!
+//! The internal structures (positions, the matrix's rows) are rebuilt when reading a description, so a hand-edited or
+//! truncated description results in an error instead of an inconsistent model.
+
+use crate::{
+    LinprogError, Model, Objective, Operator, SolverOptions, Summand, Var, VarType, Variable,
+};
+use serde::{Deserialize, Serialize, Serializer};
+use std::convert::TryFrom;
+
+#[derive(Serialize, Deserialize)]
+pub struct ModelDescription {
+    name: String,
+    objective: Objective,
+    variables: Vec<VariableDescription>,
+    constraints: Vec<ConstraintDescription>,
+    options: SolverOptions,
+    node_limit: Option<usize>,
+    gap_tolerance: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VariableDescription {
+    name: Option<String>,
+    objective_value: f64,
+    var_type: VarType,
+    #[serde(with = "crate::serde_float")]
+    lower: f64,
+    #[serde(with = "crate::serde_float")]
+    upper: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ConstraintDescription {
+    name: Option<String>,
+    /// The coefficients of `a x` as `(variable, value)`, the variable given by its position in `variables`
+    coefficients: Vec<(usize, f64)>,
+    operator: Operator,
+    #[serde(with = "crate::serde_float")]
+    rhs: f64,
+    /// The range of `a x`, see [`from_mps`](struct.Model.html#method.from_mps): `inf` for inequalities, zero for
+    /// equalities, otherwise `a x` is within `[b - range, b]` for `<=` and `[b, b + range]` for `>=`
+    #[serde(with = "crate::serde_float")]
+    range: f64,
+}
+
+impl From<&Model> for ModelDescription {
+    fn from(model: &Model) -> Self {
+        let variables = model
+            .variables
+            .iter()
+            .map(|variable| VariableDescription {
+                name: variable.name.clone(),
+                objective_value: variable.objective_value,
+                var_type: variable.var_type,
+                lower: variable.lower,
+                upper: variable.upper,
+            })
+            .collect();
+        let constraints = model
+            .constraints
+            .iter()
+            .map(|constraint| {
+                // The factor is the sign the row was registered with
+                let operator = if constraint.factor < 0f64 {
+                    Operator::Ge
+                } else if constraint.range == 0f64 {
+                    Operator::E
+                } else {
+                    Operator::Le
+                };
+                ConstraintDescription {
+                    name: constraint.name.clone(),
+                    coefficients: model
+                        .matrix
+                        .row(constraint.row)
+                        .map(|(variable, value)| (variable, constraint.factor * value))
+                        .collect(),
+                    operator,
+                    rhs: constraint.factor * model.rhs[constraint.row],
+                    range: constraint.range,
+                }
+            })
+            .collect();
+        ModelDescription {
+            name: model.name.clone(),
+            objective: model.objective,
+            variables,
+            constraints,
+            options: model.options.clone(),
+            node_limit: model.node_limit,
+            gap_tolerance: model.gap_tolerance,
+        }
+    }
+}
+
+impl TryFrom<ModelDescription> for Model {
+    type Error = LinprogError;
+
+    fn try_from(description: ModelDescription) -> Result<Self, Self::Error> {
+        let mut model = Model::new(&description.name, description.objective);
+        model.options = description.options;
+        model.node_limit = description.node_limit;
+        model.gap_tolerance = description.gap_tolerance;
+        let vars: Vec<Var> = description
+            .variables
+            .into_iter()
+            .map(|variable| {
+                let var =
+                    model.reg_var_bounded(variable.objective_value, variable.lower, variable.upper);
+                let registered: &mut Variable = model.variables.last_mut().unwrap();
+                registered.name = variable.name;
+                registered.var_type = variable.var_type;
+                var
+            })
+            .collect();
+        for constraint in description.constraints {
+            let sum = constraint
+                .coefficients
+                .iter()
+                .map(|&(variable, value)| {
+                    vars.get(variable)
+                        .map(|var| Summand(value, var))
+                        .ok_or(LinprogError::ForeignVariable)
+                })
+                .collect::<Result<Vec<Summand>, LinprogError>>()?;
+            let valid_range = match constraint.operator {
+                Operator::E => constraint.range == 0f64,
+                Operator::Ge | Operator::Le => constraint.range >= 0f64,
+            };
+            if !valid_range {
+                return Result::Err(LinprogError::InvalidModel(
+                    "A constraint's range must be zero for equalities and non-negative otherwise",
+                ));
+            }
+            model.reg_constr_overload(sum, constraint.operator, constraint.rhs, constraint.name)?;
+            model.constraints.last_mut().unwrap().range = constraint.range;
+        }
+        Result::Ok(model)
+    }
+}
+
+impl Serialize for Model {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ModelDescription::from(self).serialize(serializer)
+    }
+}
@@ -0,0 +1,83 @@
+//! The error type of the library.
+
+use crate::{ParseError, SolveStatus};
+use std::fmt;
+
+/// An error returned by the methods of a [`Model`](struct.model.html).
+///
+/// The methods panicking at handles of other models have `try_` variants returning
+/// [`ForeignVariable`](enum.LinprogError.html#variant.ForeignVariable) or
+/// [`ForeignConstraint`](enum.LinprogError.html#variant.ForeignConstraint) instead.
+#[derive(PartialEq, Clone, Debug)]
+pub enum LinprogError {
+    /// The model has not been optimized since its last change
+    NotOptimized,
+    /// The optimization proved the model infeasible, so there is no solution
+    Infeasible,
+    /// The optimization proved the model unbounded, so there is no optimal solution
+    Unbounded,
+    /// The optimization stopped without a solution, with the given status
+    NoSolution(SolveStatus),
+    /// The optimization did not compute the requested values, e.g. dual values of a model with integer variables
+    NotAvailable(&'static str),
+    /// The variable is not registered for the model: It belongs to another model or was removed
+    ForeignVariable,
+    /// The constraint is not registered for the model: It belongs to another model or was removed
+    ForeignConstraint,
+    /// An irreducible infeasible subsystem was requested for a model which is not infeasible
+    NotInfeasible,
+    /// A name can not be written in the file format
+    InvalidName(&'static str),
+    /// A model description, e.g. a deserialized one, is inconsistent
+    InvalidModel(&'static str),
+    /// A model file can not be read
+    Parse(ParseError),
+}
+
+impl LinprogError {
+    /// Returns the error for a missing solution of an optimization stopped with the given status.
+    pub(crate) fn no_solution(status: SolveStatus) -> Self {
+        match status {
+            SolveStatus::Infeasible => LinprogError::Infeasible,
+            SolveStatus::Unbounded => LinprogError::Unbounded,
+            status => LinprogError::NoSolution(status),
+        }
+    }
+}
+
+impl fmt::Display for LinprogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinprogError::NotOptimized => write!(f, "Model not optimized"),
+            LinprogError::Infeasible => write!(f, "Model is infeasible"),
+            LinprogError::Unbounded => write!(f, "Model is unbounded"),
+            LinprogError::NoSolution(status) => write!(f, "No solution available: {}", status),
+            LinprogError::NotAvailable(values) => write!(f, "No {} available", values),
+            LinprogError::ForeignVariable => write!(f, "Variable not registered for this model"),
+            LinprogError::ForeignConstraint => {
+                write!(f, "Constraint not registered for this model")
+            }
+            LinprogError::NotInfeasible => write!(f, "Model is not infeasible"),
+            LinprogError::InvalidName(reason) => write!(f, "{}", reason),
+            LinprogError::InvalidModel(reason) => write!(f, "{}", reason),
+            LinprogError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for LinprogError {}
+
+impl From<ParseError> for LinprogError {
+    fn from(error: ParseError) -> Self {
+        LinprogError::Parse(error)
+    }
+}
+
+/// Panics at the errors of handles not registered for the model, as the methods without `try_` do.
+pub(crate) fn registered<T>(result: Result<T, LinprogError>) -> Result<T, LinprogError> {
+    match result {
+        Result::Err(error @ LinprogError::ForeignVariable)
+        | Result::Err(error @ LinprogError::ForeignConstraint) => panic!("{}", error),
+        result => result,
+    }
+}
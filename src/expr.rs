@@ -0,0 +1,418 @@
+//! Linear expressions built from variables with arithmetic operators.
+
+use crate::{Constr, LinprogError, Model, Operator, Summand, Var};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A linear expression `a x + c` of variables, built with `+`, `-` and scalar `*`, e.g. `2.0 * x - 3.0 * y + 1.0`.
+///
+/// Variables may appear several times, their coefficients are summed up when the expression is registered.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct LinExpr {
+    terms: Vec<(f64, Var)>,
+    constant: f64,
+}
+
+impl LinExpr {
+    /// Creates an empty [`LinExpr`](struct.LinExpr.html), equal to zero.
+    pub fn new() -> Self {
+        LinExpr::default()
+    }
+
+    /// Returns the expression's terms as pairs of coefficient and variable.
+    pub fn terms(&self) -> &[(f64, Var)] {
+        &self.terms
+    }
+
+    /// Returns the expression's constant.
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    fn scale(mut self, factor: f64) -> Self {
+        for term in self.terms.iter_mut() {
+            term.0 *= factor;
+        }
+        self.constant *= factor;
+        self
+    }
+}
+
+impl From<Var> for LinExpr {
+    fn from(var: Var) -> Self {
+        LinExpr {
+            terms: vec![(1f64, var)],
+            constant: 0f64,
+        }
+    }
+}
+
+impl From<f64> for LinExpr {
+    fn from(constant: f64) -> Self {
+        LinExpr {
+            terms: vec![],
+            constant,
+        }
+    }
+}
+
+impl<'a> From<Summand<'a>> for LinExpr {
+    fn from(summand: Summand<'a>) -> Self {
+        LinExpr {
+            terms: vec![(summand.0, *summand.1)],
+            constant: 0f64,
+        }
+    }
+}
+
+impl<T: Into<LinExpr>> AddAssign<T> for LinExpr {
+    fn add_assign(&mut self, other: T) {
+        let other = other.into();
+        self.terms.extend(other.terms);
+        self.constant += other.constant;
+    }
+}
+
+impl<T: Into<LinExpr>> SubAssign<T> for LinExpr {
+    fn sub_assign(&mut self, other: T) {
+        *self += other.into().scale(-1f64);
+    }
+}
+
+impl<T: Into<LinExpr>> Add<T> for LinExpr {
+    type Output = LinExpr;
+
+    fn add(mut self, other: T) -> LinExpr {
+        self += other;
+        self
+    }
+}
+
+impl<T: Into<LinExpr>> Sub<T> for LinExpr {
+    type Output = LinExpr;
+
+    fn sub(mut self, other: T) -> LinExpr {
+        self -= other;
+        self
+    }
+}
+
+impl Neg for LinExpr {
+    type Output = LinExpr;
+
+    fn neg(self) -> LinExpr {
+        self.scale(-1f64)
+    }
+}
+
+impl Mul<f64> for LinExpr {
+    type Output = LinExpr;
+
+    fn mul(self, factor: f64) -> LinExpr {
+        self.scale(factor)
+    }
+}
+
+impl Mul<LinExpr> for f64 {
+    type Output = LinExpr;
+
+    fn mul(self, expr: LinExpr) -> LinExpr {
+        expr.scale(self)
+    }
+}
+
+impl<T: Into<LinExpr>> Add<T> for Var {
+    type Output = LinExpr;
+
+    fn add(self, other: T) -> LinExpr {
+        LinExpr::from(self) + other
+    }
+}
+
+impl<T: Into<LinExpr>> Sub<T> for Var {
+    type Output = LinExpr;
+
+    fn sub(self, other: T) -> LinExpr {
+        LinExpr::from(self) - other
+    }
+}
+
+impl Neg for Var {
+    type Output = LinExpr;
+
+    fn neg(self) -> LinExpr {
+        -LinExpr::from(self)
+    }
+}
+
+impl Mul<f64> for Var {
+    type Output = LinExpr;
+
+    fn mul(self, factor: f64) -> LinExpr {
+        LinExpr::from(self).scale(factor)
+    }
+}
+
+impl Mul<Var> for f64 {
+    type Output = LinExpr;
+
+    fn mul(self, var: Var) -> LinExpr {
+        LinExpr::from(var).scale(self)
+    }
+}
+
+impl Add<Var> for f64 {
+    type Output = LinExpr;
+
+    fn add(self, var: Var) -> LinExpr {
+        LinExpr::from(self) + var
+    }
+}
+
+impl Add<LinExpr> for f64 {
+    type Output = LinExpr;
+
+    fn add(self, expr: LinExpr) -> LinExpr {
+        expr + self
+    }
+}
+
+impl Sub<Var> for f64 {
+    type Output = LinExpr;
+
+    fn sub(self, var: Var) -> LinExpr {
+        LinExpr::from(self) - var
+    }
+}
+
+impl Sub<LinExpr> for f64 {
+    type Output = LinExpr;
+
+    fn sub(self, expr: LinExpr) -> LinExpr {
+        LinExpr::from(self) - expr
+    }
+}
+
+impl<T: Into<LinExpr>> Sum<T> for LinExpr {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(LinExpr::new(), |sum, expr| sum + expr)
+    }
+}
+
+impl Model {
+    /// Registers a constraint `expr op b` given by a [`LinExpr`](struct.LinExpr.html), or anything converting into
+    /// one such as a single variable, and returns its handle. The expression's constant is moved to the right side.
+    /// # Panics
+    /// This method panics if one of the variables in the expression is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn add_constr<T: Into<LinExpr>>(&mut self, expr: T, op: Operator, b: f64) -> Constr {
+        self.try_add_constr(expr, op, b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Registers a constraint `expr op b` and returns its handle, see [`add_constr`](#method.add_constr).
+    /// # Errors
+    /// This method will return an Error if one of the variables in the expression is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn try_add_constr<T: Into<LinExpr>>(
+        &mut self,
+        expr: T,
+        op: Operator,
+        b: f64,
+    ) -> Result<Constr, LinprogError> {
+        self.add_constr_overload(expr.into(), op, b, Option::None)
+    }
+
+    /// Registers a constraint `expr op b`, with a given name, like [`add_constr`](#method.add_constr) and returns its
+    /// handle.
+    /// # Panics
+    /// This method panics if one of the variables in the expression is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn add_constr_with_name<T: Into<LinExpr>>(
+        &mut self,
+        expr: T,
+        op: Operator,
+        b: f64,
+        name: &str,
+    ) -> Constr {
+        self.try_add_constr_with_name(expr, op, b, name)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Registers a constraint `expr op b`, with a given name, and returns its handle, see
+    /// [`add_constr_with_name`](#method.add_constr_with_name).
+    /// # Errors
+    /// This method will return an Error if one of the variables in the expression is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn try_add_constr_with_name<T: Into<LinExpr>>(
+        &mut self,
+        expr: T,
+        op: Operator,
+        b: f64,
+        name: &str,
+    ) -> Result<Constr, LinprogError> {
+        self.add_constr_overload(expr.into(), op, b, Option::Some(String::from(name)))
+    }
+
+    fn add_constr_overload(
+        &mut self,
+        expr: LinExpr,
+        op: Operator,
+        b: f64,
+        name: Option<String>,
+    ) -> Result<Constr, LinprogError> {
+        let sum = expr
+            .terms
+            .iter()
+            .map(|(factor, var)| Summand(*factor, var))
+            .collect();
+        self.reg_constr_overload(sum, op, b - expr.constant, name)
+    }
+
+    /// Sets the objective to a [`LinExpr`](struct.LinExpr.html), the variables not in it getting an objective value
+    /// of `0`. The expression's constant is ignored, as it does not change the optimal solutions.
+    /// The next optimization starts from the last optimal basis.
+    /// # Panics
+    /// This method panics if one of the variables in the expression is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn set_objective<T: Into<LinExpr>>(&mut self, expr: T) -> &mut Self {
+        self.try_set_objective(expr)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Sets the objective to a [`LinExpr`](struct.LinExpr.html), see [`set_objective`](#method.set_objective).
+    /// The objective is left unchanged on an Error.
+    /// # Errors
+    /// This method will return an Error if one of the variables in the expression is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn try_set_objective<T: Into<LinExpr>>(
+        &mut self,
+        expr: T,
+    ) -> Result<&mut Self, LinprogError> {
+        let expr = expr.into();
+        let mut objective_values = vec![0f64; self.variables.len()];
+        for (factor, var) in expr.terms.iter() {
+            objective_values[self.try_variable_position(var)?] += factor;
+        }
+        self.reset_solution();
+        for (variable, objective_value) in self.variables.iter_mut().zip(objective_values) {
+            variable.objective_value = objective_value;
+        }
+        Result::Ok(self)
+    }
+}
+
+/// Builds a [`Model`](struct.model.html) from an objective (`max` or `min`) and constraints separated by `;`,
+/// returning it with a `HashMap` of the variable handles by name.
+///
+/// Terms are variables with an optional coefficient, `3 x` or `3 * x`, or constants. Constraints compare two sums
+/// with `<=`, `>=` or `==`. Variables are registered in the order they first appear in, with their names, and are
+/// non-negative. As the macro reads the tokens one by one, large models may need a higher `recursion_limit`.
+///
+/// ```
+/// use linprog::{lp, Operator};
+///
+/// let (mut model, vars) = lp! {
+///     max 3 x + 5 y;
+///     x + 2 y <= 170;
+///     x + y <= 150;
+///     3 * y <= 180;
+/// };
+/// model.add_constr(vars["x"] - vars["y"], Operator::Ge, 0.0);
+/// model.optimize();
+/// assert!((model.optimum().unwrap() - 490.0).abs() < 1e-9);
+/// ```
+///
+/// Unlike the LP format, a coefficient must be separated from its variable: Rust reads `3x` as the number `3` with the
+/// invalid suffix `x`, so it does not compile.
+///
+/// ```compile_fail
+/// use linprog::lp;
+///
+/// let (model, vars) = lp! {
+///     max 3x + 5y;
+///     x + 2y <= 170;
+/// };
+/// ```
+#[macro_export]
+macro_rules! lp {
+    (max $($rest:tt)*) => {
+        $crate::lp!(@model $crate::Objective::Max, $($rest)*)
+    };
+    (min $($rest:tt)*) => {
+        $crate::lp!(@model $crate::Objective::Min, $($rest)*)
+    };
+    (@model $objective:expr, $($rest:tt)*) => {{
+        let mut model = $crate::Model::new("", $objective);
+        let mut vars: ::std::collections::HashMap<&'static str, $crate::Var> =
+            ::std::collections::HashMap::new();
+        $crate::lp!(@objective model vars [] $($rest)*);
+        (model, vars)
+    }};
+    // Collects the objective's tokens up to the first `;`
+    (@objective $model:ident $vars:ident [$($objective:tt)*] ; $($rest:tt)*) => {
+        $crate::lp!(@objective $model $vars [$($objective)*]);
+        $crate::lp!(@constraint $model $vars [] $($rest)*);
+    };
+    (@objective $model:ident $vars:ident [$($objective:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::lp!(@objective $model $vars [$($objective)* $next] $($rest)*);
+    };
+    (@objective $model:ident $vars:ident [$($objective:tt)*]) => {
+        let objective = $crate::lp!(@expr $model $vars $($objective)*);
+        $model.set_objective(objective);
+    };
+    // Collects a constraint's left side up to its comparison
+    (@constraint $model:ident $vars:ident []) => {};
+    (@constraint $model:ident $vars:ident [$($lhs:tt)*] <= $($rest:tt)*) => {
+        $crate::lp!(@rhs $model $vars [$($lhs)*] Le [] $($rest)*);
+    };
+    (@constraint $model:ident $vars:ident [$($lhs:tt)*] >= $($rest:tt)*) => {
+        $crate::lp!(@rhs $model $vars [$($lhs)*] Ge [] $($rest)*);
+    };
+    (@constraint $model:ident $vars:ident [$($lhs:tt)*] == $($rest:tt)*) => {
+        $crate::lp!(@rhs $model $vars [$($lhs)*] E [] $($rest)*);
+    };
+    (@constraint $model:ident $vars:ident [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::lp!(@constraint $model $vars [$($lhs)* $next] $($rest)*);
+    };
+    // Collects a constraint's right side up to the next `;`
+    (@rhs $model:ident $vars:ident [$($lhs:tt)*] $op:ident [$($rhs:tt)*] ; $($rest:tt)*) => {
+        $crate::lp!(@rhs $model $vars [$($lhs)*] $op [$($rhs)*]);
+        $crate::lp!(@constraint $model $vars [] $($rest)*);
+    };
+    (@rhs $model:ident $vars:ident [$($lhs:tt)*] $op:ident [$($rhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::lp!(@rhs $model $vars [$($lhs)*] $op [$($rhs)* $next] $($rest)*);
+    };
+    (@rhs $model:ident $vars:ident [$($lhs:tt)*] $op:ident [$($rhs:tt)*]) => {
+        let expr = $crate::lp!(@expr $model $vars $($lhs)*) - $crate::lp!(@expr $model $vars $($rhs)*);
+        $model.add_constr(expr, $crate::Operator::$op, 0f64);
+    };
+    // Sums up the terms, the first one's sign being optional
+    (@expr $model:ident $vars:ident - $($tokens:tt)+) => {
+        $crate::lp!(@terms $model $vars ($crate::LinExpr::new()) - $($tokens)+)
+    };
+    (@expr $model:ident $vars:ident $($tokens:tt)+) => {
+        $crate::lp!(@terms $model $vars ($crate::LinExpr::new()) + $($tokens)+)
+    };
+    (@terms $model:ident $vars:ident ($sum:expr)) => {
+        $sum
+    };
+    (@terms $model:ident $vars:ident ($sum:expr) $sign:tt $coefficient:literal * $var:ident $($rest:tt)*) => {
+        $crate::lp!(@terms $model $vars ($sum $sign ($coefficient as f64) * $crate::lp!(@var $model $vars $var)) $($rest)*)
+    };
+    (@terms $model:ident $vars:ident ($sum:expr) $sign:tt $coefficient:literal $var:ident $($rest:tt)*) => {
+        $crate::lp!(@terms $model $vars ($sum $sign ($coefficient as f64) * $crate::lp!(@var $model $vars $var)) $($rest)*)
+    };
+    (@terms $model:ident $vars:ident ($sum:expr) $sign:tt $var:ident $($rest:tt)*) => {
+        $crate::lp!(@terms $model $vars ($sum $sign $crate::lp!(@var $model $vars $var)) $($rest)*)
+    };
+    (@terms $model:ident $vars:ident ($sum:expr) $sign:tt $constant:literal $($rest:tt)*) => {
+        $crate::lp!(@terms $model $vars ($sum $sign ($constant as f64)) $($rest)*)
+    };
+    // Returns a variable's handle, registering it on its first appearance
+    (@var $model:ident $vars:ident $var:ident) => {
+        *$vars
+            .entry(stringify!($var))
+            .or_insert_with(|| $model.reg_var_with_name(0f64, stringify!($var)))
+    };
+}
@@ -0,0 +1,104 @@
+//! Infeasibility diagnosis by an irreducible infeasible subsystem.
+
+use crate::solver::{self, Problem};
+use crate::sparse::SparseMatrix;
+use crate::{Constr, LinprogError, Model, SolveStatus, SolverOptions, Var, VarType};
+
+/// An irreducible infeasible subsystem (IIS): Constraints and variable bounds which are infeasible together, but
+/// feasible if any one of them is removed.
+#[derive(PartialEq, Debug)]
+pub struct Iis {
+    /// The constraints of the subsystem, in registration order
+    pub constrs: Vec<Constr>,
+    /// The variables whose lower bound is part of the subsystem
+    pub lower_bounds: Vec<Var>,
+    /// The variables whose upper bound is part of the subsystem
+    pub upper_bounds: Vec<Var>,
+}
+
+/// Returns the problem restricted to the rows kept.
+fn restrict(problem: &Problem, kept: &[bool]) -> Problem {
+    let mut constraints = SparseMatrix::new();
+    let mut rhs = vec![];
+    let mut range = vec![];
+    for row in (0..problem.rhs.len()).filter(|&row| kept[row]) {
+        constraints.push_row(problem.constraints.row(row).collect());
+        rhs.push(problem.rhs[row]);
+        range.push(problem.range[row]);
+    }
+    Problem {
+        constraints,
+        rhs,
+        range,
+        ranged_rhs: vec![],
+        ..problem.clone()
+    }
+}
+
+impl Model {
+    /// Computes an irreducible infeasible subsystem of the [`Model`](struct.model.html)'s linear relaxation
+    /// (binary variables ranging from `0` to `1`), see [`Iis`](struct.Iis.html).
+    ///
+    /// The subsystem is found by a deletion filter: Every constraint and finite bound is dropped if the model stays
+    /// infeasible without it. Subproblems stopping without a result (e.g. at the iteration limit) count as feasible,
+    /// so the subsystem is infeasible, but may not be irreducible then.
+    /// # Errors
+    /// This method will return an Error if the linear relaxation is not infeasible.
+    pub fn iis(&self) -> Result<Iis, LinprogError> {
+        let binary_bounds: Vec<(usize, f64, f64)> = self
+            .variables
+            .iter()
+            .enumerate()
+            .filter(|(_, variable)| variable.var_type == VarType::Binary)
+            .map(|(index, _)| (index, 0f64, 1f64))
+            .collect();
+        let mut problem = self.relaxation(&binary_bounds);
+        let options = SolverOptions {
+            sensitivity: false,
+            ..self.options.clone()
+        };
+        let mut kept = vec![true; self.rhs.len()];
+        let is_infeasible = |problem: &Problem, kept: &[bool]| {
+            solver::solve(&restrict(problem, kept), &options).status == SolveStatus::Infeasible
+        };
+        if !is_infeasible(&problem, &kept) {
+            return Result::Err(LinprogError::NotInfeasible);
+        }
+        for constraint in self.constraints.iter() {
+            kept[constraint.row] = false;
+            if !is_infeasible(&problem, &kept) {
+                kept[constraint.row] = true;
+            }
+        }
+        for variable in 0..self.variables.len() {
+            let lower = problem.lower[variable];
+            if lower.is_finite() {
+                problem.lower[variable] = -1.0 / 0.0;
+                if !is_infeasible(&problem, &kept) {
+                    problem.lower[variable] = lower;
+                }
+            }
+            let upper = problem.upper[variable];
+            if upper.is_finite() {
+                problem.upper[variable] = 1.0 / 0.0;
+                if !is_infeasible(&problem, &kept) {
+                    problem.upper[variable] = upper;
+                }
+            }
+        }
+        let variables_where = |bounds: &[f64]| {
+            (0..self.variables.len())
+                .filter(|&position| bounds[position].is_finite())
+                .map(|position| self.var_handle(position))
+                .collect()
+        };
+        Result::Ok(Iis {
+            constrs: (0..self.constraints.len())
+                .filter(|&position| kept[self.constraints[position].row])
+                .map(|position| self.constr_handle(position))
+                .collect(),
+            lower_bounds: variables_where(&problem.lower),
+            upper_bounds: variables_where(&problem.upper),
+        })
+    }
+}
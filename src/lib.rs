@@ -2,35 +2,91 @@
 //!
 //! Providing an interface to optimize linear programs.
 //!
-//! This library does not (yet) support mixed integer programming.
+//! Variables can be restricted to integer or binary values, in which case the
+//! [`Model`](struct.model.html) is optimized using branch-and-bound.
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "serde")]
+mod description;
+mod error;
+mod expr;
+mod iis;
+mod lp_format;
+mod mip;
+mod mps;
+#[cfg(feature = "serde")]
+mod serde_float;
+mod solution;
 mod solver;
+mod sparse;
 
+pub use error::LinprogError;
+pub use expr::LinExpr;
+pub use iis::Iis;
+pub use mps::MpsFormat;
+pub use solution::Solution;
+pub use solver::{
+    AntiCycling, Backend, Initialization, Pricing, SolveStatus, SolverOptions, Tolerances,
+    BIG_M_RATIO, BIG_M_WARNING,
+};
+
+use solver::Problem;
+use sparse::SparseMatrix;
+use std::collections::HashMap;
 use std::fmt;
-use uuid::Uuid;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The identifier of the next [`Model`](struct.model.html), telling the handles of different models apart.
+static NEXT_MODEL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a new identifier for a [`Model`](struct.model.html).
+fn next_model_id() -> usize {
+    NEXT_MODEL_ID.fetch_add(1, Ordering::Relaxed)
+}
 
 /// Representation of a linear program.
+///
+/// With the `serde` feature, models can be serialized with their objective, variables, constraints, names and
+/// options. A deserialized model is not optimized and gets handles of its own, see [`vars`](#method.vars).
+/// Deserializing an inconsistent description, e.g. with a constraint on a variable not part of it, fails with an error.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(try_from = "description::ModelDescription")
+)]
 pub struct Model {
+    /// The identifier stored in the model's handles
+    id: usize,
     name: String,
-    state: State,
     objective: Objective,
     variables: Vec<Variable>,
-    constraints: Vec<Vec<f64>>,
-    tableau: Vec<Vec<f64>>,
+    constraints: Vec<Constraint>,
+    /// The positions of the variables by handle index, `None` for removed ones
+    variable_positions: Vec<Option<usize>>,
+    /// The positions of the constraints by handle index, `None` for removed ones
+    constraint_positions: Vec<Option<usize>>,
+    /// The coefficients of the constraints' rows in the form `a x <= b` or `a x = b`
+    matrix: SparseMatrix,
+    /// The rows' right sides `b`
+    rhs: Vec<f64>,
+    options: SolverOptions,
+    status: Option<SolveStatus>,
     optimum: Option<f64>,
-}
-
-#[derive(PartialEq)]
-enum State {
-    VariableRegistration,
-    ConstraintRegistration,
-    PostRegistration,
+    iteration_count: usize,
+    node_limit: Option<usize>,
+    gap_tolerance: f64,
+    best_bound: Option<f64>,
+    node_count: usize,
+    /// The last optimal basis of the linear program, the next optimization starts from
+    basis: Option<Vec<solver::BasisStatus>>,
+    warnings: Vec<&'static str>,
 }
 
 /// A linear program's objective.
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Objective {
     /// Maximize
     Max,
@@ -41,20 +97,92 @@ pub enum Objective {
 #[derive(PartialEq)]
 struct Variable {
     name: Option<String>,
-    uuid: Uuid,
+    /// The index of the variable's handle
+    index: usize,
     x: Option<f64>,
     objective_value: f64,
+    var_type: VarType,
+    lower: f64,
+    upper: f64,
+    reduced_cost: Option<f64>,
+    cost_range: Option<(f64, f64)>,
+    /// The variable's direction in an unbounded ray
+    ray: Option<f64>,
+}
+
+#[derive(PartialEq)]
+struct Constraint {
+    name: Option<String>,
+    /// The index of the constraint's handle
+    index: usize,
+    /// The constraint's row in `Model::matrix`
+    row: usize,
+    /// The factor the constraint was multiplied by for its row, turning the row's dual value into the constraint's
+    factor: f64,
+    /// The range below the row's right side within which `a x` may be: `inf` for inequalities, zero for equalities
+    range: f64,
+    dual: Option<f64>,
+    rhs_range: Option<(f64, f64)>,
+    /// The constraint's multiplier in a Farkas certificate
+    farkas: Option<f64>,
+}
+
+/// A variable's domain.
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VarType {
+    /// Any value within the variable's bounds
+    Continuous,
+    /// Integer values within the variable's bounds
+    Integer,
+    /// Either `0` or `1`
+    Binary,
 }
 
 /// A representation of a variable used in the linear program.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct Var {
-    reference: Uuid,
+    /// The identifier of the model the variable is registered for
+    model: usize,
+    /// The variable's registration index within the model
+    index: usize,
+}
+
+/// A representation of a constraint used in the linear program.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Constr {
+    /// The identifier of the model the constraint is registered for
+    model: usize,
+    /// The constraint's registration index within the model
+    index: usize,
 }
 
 /// A pair of factor and variable for constructing sums.
 pub struct Summand<'a>(pub f64, pub &'a Var);
 
+/// An error in a model file, at a line and a column (both starting at 1).
+#[derive(PartialEq, Clone, Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// A constraint's comparing operator.
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operator {
     /// Greater or equal: `>=`
     Ge,
@@ -68,230 +196,895 @@ impl Model {
     /// Creates a new [`Model`](struct.model.html). A representation of a linear program.
     pub fn new(name: &str, objective: Objective) -> Self {
         Model {
+            id: next_model_id(),
             name: String::from(name),
-            state: State::VariableRegistration,
             objective,
             variables: vec![],
             constraints: vec![],
-            tableau: vec![],
+            variable_positions: vec![],
+            constraint_positions: vec![],
+            matrix: SparseMatrix::new(),
+            rhs: vec![],
+            options: SolverOptions::default(),
+            status: Option::None,
             optimum: Option::None,
+            iteration_count: 0,
+            node_limit: Option::None,
+            gap_tolerance: 1e-4,
+            best_bound: Option::None,
+            node_count: 0,
+            basis: Option::None,
+            warnings: vec![],
         }
     }
 
-    /// Submits the registered variables or constraints to the [`Model`](struct.model.html) and changes it's phase to the next.
-    /// This method can be called implicitly by calling [`reg_constr`](#method.reg_constr) or [`optimize`](#method.optimize).
-    ///
-    /// The [`Model`](struct.model.html)'s lifetime follows three strictly seperated phases:
-    ///
-    ///  - In the first phase, variables can be registered.
-    ///  - In the second phase, constraints can be registered.
-    ///  - In the third phase, the [`Model`](struct.model.html) can be optimized.
-    ///
-    /// After the variables or constraints are submitted to the [`Model`](struct.model.html), they can not be changed again (The phases can not be reverted or modified).
+    /// Has no effect: Variables and constraints can be registered, changed and removed at any time.
+    /// Kept for compatibility with the former registration phases.
     pub fn update(&mut self) -> &mut Self {
-        match self.state {
-            State::VariableRegistration => self.state = State::ConstraintRegistration,
-            State::ConstraintRegistration => self.state = State::PostRegistration,
-            State::PostRegistration => (),
-        }
         self
     }
 
     /// Registers a variable for the [`Model`](struct.model.html).
-    /// # Panics
-    /// This method panics if the variables were already submitted. See [`update`](#method.update).
     pub fn reg_var(&mut self, objective_value: f64) -> Var {
         self.reg_var_overload(objective_value, Option::None)
     }
 
+    /// Registers a variable with the bounds `lower <= x <= upper` for the [`Model`](struct.model.html).
+    /// Variables registered by [`reg_var`](#method.reg_var) have the bounds `0` and `inf`.
+    ///
+    /// The bounds may be infinite, `-inf` and `inf` giving a free variable. They are handled by the simplex algorithm
+    /// directly instead of adding constraints. A [`Model`](struct.model.html) with `lower > upper` is infeasible.
+    pub fn reg_var_bounded(&mut self, objective_value: f64, lower: f64, upper: f64) -> Var {
+        let var = self.reg_var_overload(objective_value, Option::None);
+        let variable = self.variables.last_mut().unwrap();
+        variable.lower = lower;
+        variable.upper = upper;
+        var
+    }
+
     /// Registers a variable, with a given name, for the [`Model`](struct.model.html).
-    /// # Panics
-    /// This method panics if the variables were already submitted. See [`update`](#method.update).
     pub fn reg_var_with_name(&mut self, objective_value: f64, name: &str) -> Var {
         self.reg_var_overload(objective_value, Option::Some(String::from(name)))
     }
 
     fn reg_var_overload(&mut self, objective_value: f64, name: Option<String>) -> Var {
-        if let State::VariableRegistration = self.state {
-            self.variables.push(Variable {
-                name,
-                uuid: Uuid::new_v4(),
-                x: Option::None,
-                objective_value,
-            });
-        } else {
-            panic!("Variables are already set");
+        self.reset_solution();
+        if let Option::Some(basis) = &mut self.basis {
+            // The new variable starts at zero, outside the basis
+            basis.insert(self.variables.len(), solver::BasisStatus::AtLower);
         }
+        let index = self.variable_positions.len();
+        self.variable_positions
+            .push(Option::Some(self.variables.len()));
+        self.variables.push(Variable {
+            name,
+            index,
+            x: Option::None,
+            objective_value,
+            var_type: VarType::Continuous,
+            lower: 0f64,
+            upper: 1.0 / 0.0,
+            reduced_cost: Option::None,
+            cost_range: Option::None,
+            ray: Option::None,
+        });
         Var {
-            reference: self.variables.last().unwrap().uuid,
+            model: self.id,
+            index,
         }
     }
 
-    /// Returns the optimal value for a given, registered variable.
+    /// Sets the [`VarType`](enum.VarType.html) of a registered variable. Variables are [`Continuous`](enum.VarType.html#variant.Continuous) by default.
+    ///
+    /// A [`Model`](struct.model.html) containing [`Integer`](enum.VarType.html#variant.Integer) or [`Binary`](enum.VarType.html#variant.Binary) variables is optimized using branch-and-bound.
+    /// # Panics
+    /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn set_var_type(&mut self, var: &Var, var_type: VarType) -> &mut Self {
+        self.try_set_var_type(var, var_type)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Sets the [`VarType`](enum.VarType.html) of a registered variable, see [`set_var_type`](#method.set_var_type).
     /// # Errors
-    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized. See [`optimize`](#method.optimize).
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn try_set_var_type(
+        &mut self,
+        var: &Var,
+        var_type: VarType,
+    ) -> Result<&mut Self, LinprogError> {
+        let variable = self.try_variable_position(var)?;
+        self.reset_solution();
+        self.variables[variable].var_type = var_type;
+        Result::Ok(self)
+    }
+
+    /// Sets the bounds `lower <= x <= upper` of a registered variable. See [`reg_var_bounded`](#method.reg_var_bounded).
     /// # Panics
     /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
-    pub fn x(&self, req: &Var) -> Result<f64, &'static str> {
-        for variable in &self.variables {
-            if variable.uuid == req.reference {
-                match variable.x {
-                    Some(x) => return Result::Ok(x),
-                    None => return Result::Err("Model not optimized"),
-                }
+    pub fn set_var_bounds(&mut self, var: &Var, lower: f64, upper: f64) -> &mut Self {
+        self.try_set_var_bounds(var, lower, upper)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Sets the bounds `lower <= x <= upper` of a registered variable, see [`set_var_bounds`](#method.set_var_bounds).
+    /// # Errors
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn try_set_var_bounds(
+        &mut self,
+        var: &Var,
+        lower: f64,
+        upper: f64,
+    ) -> Result<&mut Self, LinprogError> {
+        let variable = self.try_variable_position(var)?;
+        self.reset_solution();
+        self.variables[variable].lower = lower;
+        self.variables[variable].upper = upper;
+        Result::Ok(self)
+    }
+
+    /// Changes the objective value (coefficient) of a registered variable.
+    /// The next optimization starts from the last optimal basis.
+    /// # Panics
+    /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn set_objective_value(&mut self, var: &Var, objective_value: f64) -> &mut Self {
+        self.try_set_objective_value(var, objective_value)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Changes the objective value (coefficient) of a registered variable, see
+    /// [`set_objective_value`](#method.set_objective_value).
+    /// # Errors
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn try_set_objective_value(
+        &mut self,
+        var: &Var,
+        objective_value: f64,
+    ) -> Result<&mut Self, LinprogError> {
+        let variable = self.try_variable_position(var)?;
+        self.reset_solution();
+        self.variables[variable].objective_value = objective_value;
+        Result::Ok(self)
+    }
+
+    /// Removes a registered variable from the [`Model`](struct.model.html) and from all constraints.
+    /// # Panics
+    /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn remove_var(&mut self, var: &Var) -> &mut Self {
+        self.try_remove_var(var)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Removes a registered variable from the [`Model`](struct.model.html), see [`remove_var`](#method.remove_var).
+    /// # Errors
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn try_remove_var(&mut self, var: &Var) -> Result<&mut Self, LinprogError> {
+        let variable = self.try_variable_position(var)?;
+        self.reset_solution();
+        self.variables.remove(variable);
+        self.variable_positions[var.index] = Option::None;
+        for (position, variable) in self.variables.iter().enumerate().skip(variable) {
+            self.variable_positions[variable.index] = Option::Some(position);
+        }
+        self.matrix.remove_column(variable);
+        if let Option::Some(basis) = &mut self.basis {
+            basis.remove(variable);
+        }
+        Result::Ok(self)
+    }
+
+    /// Returns the position of a variable, panicking if it is not registered.
+    fn variable_position(&self, var: &Var) -> usize {
+        self.try_variable_position(var)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns the position of a variable.
+    fn try_variable_position(&self, var: &Var) -> Result<usize, LinprogError> {
+        match self.variable_positions.get(var.index) {
+            Option::Some(&Option::Some(position)) if var.model == self.id => Result::Ok(position),
+            _ => Result::Err(LinprogError::ForeignVariable),
+        }
+    }
+
+    /// Returns the position of a constraint.
+    fn try_constraint_position(&self, constr: &Constr) -> Result<usize, LinprogError> {
+        match self.constraint_positions.get(constr.index) {
+            Option::Some(&Option::Some(position)) if constr.model == self.id => {
+                Result::Ok(position)
             }
+            _ => Result::Err(LinprogError::ForeignConstraint),
         }
-        panic!("Variable not registered for this model");
     }
 
-    /// Registers a constraint.
-    /// # Panics
-    /// This method panics if the constraints were already submitted. See [`update`](#method.update).
+    /// Returns the handle of the variable at a position.
+    fn var_handle(&self, position: usize) -> Var {
+        Var {
+            model: self.id,
+            index: self.variables[position].index,
+        }
+    }
+
+    /// Returns the handle of the constraint at a position.
+    fn constr_handle(&self, position: usize) -> Constr {
+        Constr {
+            model: self.id,
+            index: self.constraints[position].index,
+        }
+    }
+
+    /// Discards the solution of the last optimization, keeping its basis.
+    fn reset_solution(&mut self) {
+        self.status = Option::None;
+        self.warnings.clear();
+        self.optimum = Option::None;
+        self.best_bound = Option::None;
+        for variable in self.variables.iter_mut() {
+            variable.x = Option::None;
+            variable.reduced_cost = Option::None;
+            variable.cost_range = Option::None;
+            variable.ray = Option::None;
+        }
+        for constraint in self.constraints.iter_mut() {
+            constraint.dual = Option::None;
+            constraint.rhs_range = Option::None;
+            constraint.farkas = Option::None;
+        }
+    }
+
+    /// Sets the [`SolverOptions`](struct.SolverOptions.html) used by [`optimize`](#method.optimize).
     ///
-    /// Or if one of the variables in sum is not registered for the calling [`Model`](struct.model.html).
-    pub fn reg_constr(&mut self, mut sum: Vec<Summand>, op: Operator, b: f64) -> &mut Self {
-        match self.state {
-            State::VariableRegistration => {
-                self.update();
-                return self.reg_constr(sum, op, b);
-            }
-            State::ConstraintRegistration => {
-                let mut reference_found: bool;
-                for summand in &sum {
-                    reference_found = false;
-                    for variable in &self.variables {
-                        if summand.1.reference == variable.uuid {
-                            reference_found = true;
-                            break;
-                        }
-                    }
-                    if !reference_found {
-                        panic!("Variable not registered for this model");
-                    }
-                }
-                match op {
-                    Operator::Ge => {
-                        for summand in &mut sum {
-                            summand.0 = -summand.0;
-                        }
-                        self.register_standard_constraint(&sum, -b);
-                    }
-                    Operator::E => {
-                        self.register_standard_constraint(&sum, b);
-                        self.reg_constr(sum, Operator::Ge, b);
-                    }
-                    Operator::Le => {
-                        self.register_standard_constraint(&sum, b);
-                    }
+    /// Changing the [`Initialization`](enum.Initialization.html) discards the last optimal basis, so the next
+    /// optimization starts with the chosen initialization instead of a warm start.
+    pub fn set_options(&mut self, options: SolverOptions) -> &mut Self {
+        self.reset_solution();
+        if options.initialization != self.options.initialization {
+            self.basis = Option::None;
+        }
+        self.options = options;
+        self
+    }
+
+    /// Limits the number of nodes (LP relaxations) solved during branch-and-bound.
+    /// If the limit is reached, the best integer solution found so far (the incumbent) is kept. See [`best_bound`](#method.best_bound).
+    pub fn set_node_limit(&mut self, node_limit: usize) -> &mut Self {
+        self.reset_solution();
+        self.node_limit = Option::Some(node_limit);
+        self
+    }
+
+    /// Sets the relative optimality gap at which branch-and-bound stops. The default is `1e-4`.
+    ///
+    /// Nodes whose bound does not improve the incumbent by more than this gap are not explored.
+    pub fn set_gap_tolerance(&mut self, gap_tolerance: f64) -> &mut Self {
+        self.reset_solution();
+        self.gap_tolerance = gap_tolerance;
+        self
+    }
+
+    /// Returns the optimal value for a given, registered variable.
+    ///
+    /// If branch-and-bound stopped early, this is the variable's value in the incumbent.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if the optimization did not yield a solution (see [`status`](#method.status)).
+    /// # Panics
+    /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn x(&self, req: &Var) -> Result<f64, LinprogError> {
+        error::registered(self.try_x(req))
+    }
+
+    /// Returns the optimal value for a given variable, see [`x`](#method.x).
+    /// # Errors
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html),
+    /// or in the cases of [`x`](#method.x).
+    pub fn try_x(&self, req: &Var) -> Result<f64, LinprogError> {
+        match (
+            self.variables[self.try_variable_position(req)?].x,
+            self.status,
+        ) {
+            (Some(x), _) => Result::Ok(x),
+            (None, Some(status)) => Result::Err(LinprogError::no_solution(status)),
+            (None, None) => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns the handle of the first registered variable with the given name, `None` if there is none.
+    pub fn var_by_name(&self, name: &str) -> Option<Var> {
+        self.variables
+            .iter()
+            .position(|variable| variable.name.as_deref() == Option::Some(name))
+            .map(|position| self.var_handle(position))
+    }
+
+    /// Returns the handle of the first registered constraint with the given name, `None` if there is none.
+    pub fn constr_by_name(&self, name: &str) -> Option<Constr> {
+        self.constraints
+            .iter()
+            .position(|constraint| constraint.name.as_deref() == Option::Some(name))
+            .map(|position| self.constr_handle(position))
+    }
+
+    /// Returns the handles of the registered variables, in the order of registration.
+    pub fn vars(&self) -> Vec<Var> {
+        (0..self.variables.len())
+            .map(|position| self.var_handle(position))
+            .collect()
+    }
+
+    /// Returns the handles of the registered constraints, in the order of registration.
+    pub fn constrs(&self) -> Vec<Constr> {
+        (0..self.constraints.len())
+            .map(|position| self.constr_handle(position))
+            .collect()
+    }
+
+    /// Registers a constraint and returns its handle.
+    /// # Panics
+    /// This method panics if one of the variables in sum is not registered for the calling [`Model`](struct.model.html).
+    pub fn reg_constr(&mut self, sum: Vec<Summand>, op: Operator, b: f64) -> Constr {
+        self.try_reg_constr(sum, op, b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Registers a constraint and returns its handle, see [`reg_constr`](#method.reg_constr).
+    /// # Errors
+    /// This method will return an Error if one of the variables in sum is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn try_reg_constr(
+        &mut self,
+        sum: Vec<Summand>,
+        op: Operator,
+        b: f64,
+    ) -> Result<Constr, LinprogError> {
+        self.reg_constr_overload(sum, op, b, Option::None)
+    }
+
+    /// Registers a constraint, with a given name, and returns its handle.
+    /// # Panics
+    /// This method panics if one of the variables in sum is not registered for the calling [`Model`](struct.model.html).
+    pub fn reg_constr_with_name(
+        &mut self,
+        sum: Vec<Summand>,
+        op: Operator,
+        b: f64,
+        name: &str,
+    ) -> Constr {
+        self.try_reg_constr_with_name(sum, op, b, name)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Registers a constraint, with a given name, and returns its handle, see
+    /// [`reg_constr_with_name`](#method.reg_constr_with_name).
+    /// # Errors
+    /// This method will return an Error if one of the variables in sum is not registered for the calling
+    /// [`Model`](struct.model.html).
+    pub fn try_reg_constr_with_name(
+        &mut self,
+        sum: Vec<Summand>,
+        op: Operator,
+        b: f64,
+        name: &str,
+    ) -> Result<Constr, LinprogError> {
+        self.reg_constr_overload(sum, op, b, Option::Some(String::from(name)))
+    }
+
+    fn reg_constr_overload(
+        &mut self,
+        mut sum: Vec<Summand>,
+        op: Operator,
+        b: f64,
+        name: Option<String>,
+    ) -> Result<Constr, LinprogError> {
+        for summand in &sum {
+            self.try_variable_position(summand.1)?;
+        }
+        self.reset_solution();
+        let row = self.rhs.len();
+        let (factor, range) = match op {
+            Operator::Ge => {
+                for summand in &mut sum {
+                    summand.0 = -summand.0;
                 }
-                self
+                self.register_standard_constraint(&sum, -b);
+                (-1f64, 1.0 / 0.0)
             }
-            State::PostRegistration => {
-                panic!("Constraints are already set");
+            Operator::E => {
+                // A single row, its slack variable is fixed to zero by the solver
+                self.register_standard_constraint(&sum, b);
+                (1f64, 0f64)
             }
+            Operator::Le => {
+                self.register_standard_constraint(&sum, b);
+                (1f64, 1.0 / 0.0)
+            }
+        };
+        if let Option::Some(basis) = &mut self.basis {
+            // The new row's slack variable extends the basis
+            basis.push(solver::BasisStatus::Basic);
         }
+        let index = self.constraint_positions.len();
+        self.constraint_positions
+            .push(Option::Some(self.constraints.len()));
+        self.constraints.push(Constraint {
+            name,
+            index,
+            row,
+            factor,
+            range,
+            dual: Option::None,
+            rhs_range: Option::None,
+            farkas: Option::None,
+        });
+        Result::Ok(Constr {
+            model: self.id,
+            index,
+        })
     }
 
-    fn register_standard_constraint(&mut self, sum: &Vec<Summand>, b: f64) -> &mut Self {
-        let mut a: f64;
-        let mut tmp: Vec<f64> = Vec::with_capacity(self.variables.len() + 1);
-        for variable in &self.variables {
-            a = 0f64;
-            for summand in sum {
-                if summand.1.reference == variable.uuid {
-                    a += summand.0;
-                }
+    /// Changes the right side of a registered constraint.
+    /// The next optimization starts from the last optimal basis.
+    /// # Panics
+    /// This method panics if the constraint is not registered for the calling [`Model`](struct.model.html).
+    pub fn set_rhs(&mut self, constr: &Constr, b: f64) -> &mut Self {
+        self.try_set_rhs(constr, b)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Changes the right side of a registered constraint, see [`set_rhs`](#method.set_rhs).
+    /// # Errors
+    /// This method will return an Error if the constraint is not registered for the calling [`Model`](struct.model.html).
+    pub fn try_set_rhs(&mut self, constr: &Constr, b: f64) -> Result<&mut Self, LinprogError> {
+        let constraint = self.try_constraint_position(constr)?;
+        self.reset_solution();
+        // The factor is the sign the row was registered with
+        let constraint = &self.constraints[constraint];
+        self.rhs[constraint.row] = constraint.factor * b;
+        Result::Ok(self)
+    }
+
+    /// Removes a registered constraint from the [`Model`](struct.model.html).
+    /// # Panics
+    /// This method panics if the constraint is not registered for the calling [`Model`](struct.model.html).
+    pub fn remove_constr(&mut self, constr: &Constr) -> &mut Self {
+        self.try_remove_constr(constr)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Removes a registered constraint from the [`Model`](struct.model.html), see
+    /// [`remove_constr`](#method.remove_constr).
+    /// # Errors
+    /// This method will return an Error if the constraint is not registered for the calling [`Model`](struct.model.html).
+    pub fn try_remove_constr(&mut self, constr: &Constr) -> Result<&mut Self, LinprogError> {
+        let constraint = self.try_constraint_position(constr)?;
+        self.reset_solution();
+        let row = self.constraints.remove(constraint).row;
+        self.constraint_positions[constr.index] = Option::None;
+        for (position, constraint) in self.constraints.iter().enumerate().skip(constraint) {
+            self.constraint_positions[constraint.index] = Option::Some(position);
+        }
+        self.matrix.remove_row(row);
+        self.rhs.remove(row);
+        if let Option::Some(basis) = &mut self.basis {
+            basis.remove(self.variables.len() + row);
+        }
+        for constraint in self.constraints.iter_mut() {
+            if constraint.row > row {
+                constraint.row -= 1;
             }
-            tmp.push(a);
         }
-        tmp.push(b);
-        self.constraints.push(tmp);
+        Result::Ok(self)
+    }
+
+    fn register_standard_constraint(&mut self, sum: &[Summand], b: f64) -> &mut Self {
+        let entries = sum
+            .iter()
+            .map(|summand| (self.variable_position(summand.1), summand.0))
+            .collect();
+        self.matrix.push_row(entries);
+        self.rhs.push(b);
         self
     }
 
-    fn init_tableau(&mut self) -> &mut Self {
-        self.tableau.clear();
-        let number_of_constraints = self.constraints.len();
-        let mut tmp: Vec<f64> =
-            Vec::with_capacity(self.variables.len() + number_of_constraints + 1);
-        match self.objective {
-            Objective::Max => self
-                .variables
-                .iter()
-                .for_each(|variable| tmp.push(variable.objective_value)),
-            Objective::Min => self
-                .variables
-                .iter()
-                .for_each(|variable| tmp.push(-variable.objective_value)),
-        }
-        for _ in 0..number_of_constraints + 1 {
-            tmp.push(0f64);
-        }
-        self.tableau.push(tmp);
-        for (column, constraint) in self.constraints.iter().enumerate() {
-            let mut tmp: Vec<f64> =
-                Vec::with_capacity(self.variables.len() + number_of_constraints + 1);
-            constraint.iter().for_each(|&value| tmp.push(value));
-            let b = tmp.pop().unwrap();
-            for current_column in 0..number_of_constraints {
-                if current_column == column {
-                    tmp.push(1f64);
-                } else {
-                    tmp.push(0f64);
-                }
-            }
-            tmp.push(b);
-            self.tableau.push(tmp);
+    /// Returns the linear program of the registered constraints, the variables' bounds tightened by additional bounds
+    /// given as `(variable, lower, upper)`.
+    fn relaxation(&self, bounds: &[(usize, f64, f64)]) -> Problem {
+        let objective = self
+            .variables
+            .iter()
+            .map(|variable| match self.objective {
+                Objective::Max => variable.objective_value,
+                Objective::Min => -variable.objective_value,
+            })
+            .collect();
+        let mut lower: Vec<f64> = self
+            .variables
+            .iter()
+            .map(|variable| variable.lower)
+            .collect();
+        let mut upper: Vec<f64> = self
+            .variables
+            .iter()
+            .map(|variable| variable.upper)
+            .collect();
+        for &(variable, bound_lower, bound_upper) in bounds {
+            lower[variable] = lower[variable].max(bound_lower);
+            upper[variable] = upper[variable].min(bound_upper);
+        }
+        let mut range = vec![1.0 / 0.0; self.rhs.len()];
+        for constraint in self.constraints.iter() {
+            range[constraint.row] = constraint.range;
+        }
+        let ranged_rhs = self
+            .constraints
+            .iter()
+            .map(|constraint| vec![(constraint.row, constraint.factor)])
+            .collect();
+        Problem {
+            objective,
+            constraints: self.matrix.clone(),
+            rhs: self.rhs.clone(),
+            range,
+            lower,
+            upper,
+            ranged_rhs,
+            basis: Option::None,
         }
-        self
     }
 
     /// Optimizes the [`Model`](struct.model.html).
-    /// # Panics
-    /// This method panics if the model is infeasable or might be degenerate.
+    ///
+    /// If integer or binary variables are registered, a branch-and-bound search is run, solving LP relaxations.
+    /// The search stops at the node limit (see [`set_node_limit`](#method.set_node_limit)) or once the gap tolerance (see [`set_gap_tolerance`](#method.set_gap_tolerance)) is met.
+    /// If no integer solution was found before the node limit, the optimum is `-inf` for maximization or `inf` for minimization.
+    ///
+    /// The outcome can be checked using [`status`](#method.status). Infeasible models have the optimum `-inf` for maximization or `inf` for minimization,
+    /// unbounded models have the optimum `inf` for maximization or `-inf` for minimization.
+    /// A model with integer variables and an unbounded LP relaxation is only unbounded if it has an integer solution,
+    /// which branch-and-bound then searches without objective. The model is infeasible if there is none.
+    ///
+    /// Changing the [`Model`](struct.model.html) discards the solution. A linear program is then optimized again starting
+    /// from the last optimal basis (warm start), if it is still feasible. Without changes, optimizing again has no effect.
     pub fn optimize(&mut self) -> &mut Self {
-        if let Option::None = self.optimum {
-            match self.state {
-                State::VariableRegistration => {
-                    self.update();
-                    self.update();
-                    return self.optimize();
-                }
-                State::ConstraintRegistration => {
-                    self.update();
-                    return self.optimize();
-                }
-                State::PostRegistration => {
-                    self.init_tableau();
-                    let (solution, value) = solver::optimize(&mut self.tableau, Option::None);
-                    match self.objective {
-                        Objective::Max => self.optimum = Option::Some(value),
-                        Objective::Min => self.optimum = Option::Some(-value),
-                    }
-                    if let Option::Some(hash_map) = solution {
-                        for (variable, x) in hash_map {
-                            self.variables[variable].x = Option::Some(x);
-                        }
-                    }
-                }
-            }
+        if self.status.is_some() {
+            return self;
+        }
+        if self
+            .variables
+            .iter()
+            .any(|variable| variable.var_type != VarType::Continuous)
+        {
+            self.branch_and_bound();
+            return self;
         }
+        let mut problem = self.relaxation(&[]);
+        problem.basis = self.basis.clone();
+        let solution = solver::solve(&problem, &self.options);
+        self.iteration_count = solution.iterations;
+        self.warnings = solution.warnings;
+        let value = match solution.status {
+            SolveStatus::IterationLimit | SolveStatus::NumericalFailure => Option::None,
+            _ => Option::Some(solution.value),
+        };
+        self.store_solution(solution.status, solution.x, value);
+        self.store_duals(solution.duals, solution.reduced_costs);
+        self.store_certificates(solution.farkas, solution.ray);
+        if let Option::Some(ranging) = solution.ranging {
+            self.store_ranging(ranging);
+        }
+        if solution.basis.is_some() {
+            self.basis = solution.basis;
+        }
+        self.best_bound = self.optimum;
         self
     }
 
+    /// Optimizes the [`Model`](struct.model.html) using the given [`SolverOptions`](struct.SolverOptions.html). See [`optimize`](#method.optimize).
+    pub fn optimize_with(&mut self, options: SolverOptions) -> &mut Self {
+        self.set_options(options);
+        self.optimize()
+    }
+
+    /// Optimizes the [`Model`](struct.model.html) and returns the [`SolveStatus`](enum.SolveStatus.html). See [`optimize`](#method.optimize).
+    pub fn solve(&mut self) -> SolveStatus {
+        self.optimize();
+        self.status.unwrap()
+    }
+
+    /// Stores a solution of the (maximizing) tableau as the [`Model`](struct.model.html)'s solution.
+    fn store_solution(
+        &mut self,
+        status: SolveStatus,
+        solution: Option<HashMap<usize, f64>>,
+        value: Option<f64>,
+    ) {
+        self.status = Option::Some(status);
+        // Adding zero turns a negative zero, e.g. the value of an empty sum, into zero
+        self.optimum = match self.objective {
+            Objective::Max => value,
+            Objective::Min => value.map(|value| -value),
+        }
+        .map(|value| value + 0f64);
+        if let Option::Some(hash_map) = solution {
+            for (variable, x) in hash_map {
+                self.variables[variable].x = Option::Some(x);
+            }
+        }
+    }
+
+    /// Stores the dual values of the (maximizing) rows as the constraints' dual values,
+    /// and the (maximizing) reduced costs as the variables' reduced costs.
+    fn store_duals(&mut self, duals: Option<Vec<f64>>, reduced_costs: Option<Vec<f64>>) {
+        let sign = match self.objective {
+            Objective::Max => 1f64,
+            Objective::Min => -1f64,
+        };
+        // Adding zero turns a negated zero into zero
+        if let Option::Some(reduced_costs) = reduced_costs {
+            for (variable, reduced_cost) in self.variables.iter_mut().zip(reduced_costs) {
+                variable.reduced_cost = Option::Some(sign * reduced_cost + 0f64);
+            }
+        }
+        if let Option::Some(duals) = duals {
+            for constraint in self.constraints.iter_mut() {
+                constraint.dual =
+                    Option::Some(sign * constraint.factor * duals[constraint.row] + 0f64);
+            }
+        }
+    }
+
+    /// Stores a Farkas certificate of the rows as the constraints' multipliers, and an unbounded ray as the
+    /// variables' directions.
+    fn store_certificates(&mut self, farkas: Option<Vec<f64>>, ray: Option<Vec<f64>>) {
+        if let Option::Some(farkas) = farkas {
+            for constraint in self.constraints.iter_mut() {
+                constraint.farkas = Option::Some(constraint.factor * farkas[constraint.row]);
+            }
+        }
+        if let Option::Some(ray) = ray {
+            for (variable, ray) in self.variables.iter_mut().zip(ray) {
+                variable.ray = Option::Some(ray);
+            }
+        }
+    }
+
+    /// Stores the (maximizing) ranges as the variables' objective ranges and the constraints' right side ranges.
+    fn store_ranging(&mut self, ranging: solver::Ranging) {
+        for (variable, (lower, upper)) in self.variables.iter_mut().zip(ranging.cost) {
+            variable.cost_range = match self.objective {
+                Objective::Max => Option::Some((lower, upper)),
+                Objective::Min => Option::Some((-upper, -lower)),
+            };
+        }
+        for (constraint, (lower, upper)) in self.constraints.iter_mut().zip(ranging.rhs) {
+            let b = constraint.factor * self.rhs[constraint.row];
+            constraint.rhs_range = Option::Some((b + lower, b + upper));
+        }
+    }
+
+    /// Returns the range of a given, registered variable's objective value within which the optimal basis stays optimal,
+    /// as lower and upper bound. The bounds may be infinite.
+    ///
+    /// Ranges are only computed for optimal linear programs if [`sensitivity`](struct.SolverOptions.html#structfield.sensitivity) is enabled.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if no ranges were computed.
+    /// # Panics
+    /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn cost_range(&self, req: &Var) -> Result<(f64, f64), LinprogError> {
+        error::registered(self.try_cost_range(req))
+    }
+
+    /// Returns the cost range of a given variable, see [`cost_range`](#method.cost_range).
+    /// # Errors
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html),
+    /// or in the cases of [`cost_range`](#method.cost_range).
+    pub fn try_cost_range(&self, req: &Var) -> Result<(f64, f64), LinprogError> {
+        match (
+            self.variables[self.try_variable_position(req)?].cost_range,
+            self.status,
+        ) {
+            (Some(range), _) => Result::Ok(range),
+            (None, Some(_)) => Result::Err(LinprogError::NotAvailable("sensitivity analysis")),
+            (None, None) => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns the range of a given, registered constraint's right side within which the optimal basis stays feasible
+    /// (and the dual values stay valid), as lower and upper bound. The bounds may be infinite.
+    ///
+    /// Ranges are only computed for optimal linear programs if [`sensitivity`](struct.SolverOptions.html#structfield.sensitivity) is enabled.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if no ranges were computed.
+    /// # Panics
+    /// This method panics if the constraint is not registered for the calling [`Model`](struct.model.html).
+    pub fn rhs_range(&self, req: &Constr) -> Result<(f64, f64), LinprogError> {
+        error::registered(self.try_rhs_range(req))
+    }
+
+    /// Returns the right side range of a given constraint, see [`rhs_range`](#method.rhs_range).
+    /// # Errors
+    /// This method will return an Error if the constraint is not registered for the calling [`Model`](struct.model.html),
+    /// or in the cases of [`rhs_range`](#method.rhs_range).
+    pub fn try_rhs_range(&self, req: &Constr) -> Result<(f64, f64), LinprogError> {
+        match (
+            self.constraints[self.try_constraint_position(req)?].rhs_range,
+            self.status,
+        ) {
+            (Some(range), _) => Result::Ok(range),
+            (None, Some(_)) => Result::Err(LinprogError::NotAvailable("sensitivity analysis")),
+            (None, None) => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns the reduced cost of a given, registered variable:
+    /// The change of the optimum per unit increase of the variable, zero for base variables.
+    ///
+    /// For an optimal solution it is non-positive when maximizing and non-negative when minimizing.
+    /// Reduced costs are only available for optimal linear programs, not for models with integer or binary variables.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if the optimization did not yield reduced costs (see [`status`](#method.status)).
+    /// # Panics
+    /// This method panics if the variable is not registered for the calling [`Model`](struct.model.html).
+    pub fn reduced_cost(&self, req: &Var) -> Result<f64, LinprogError> {
+        error::registered(self.try_reduced_cost(req))
+    }
+
+    /// Returns the reduced cost of a given variable, see [`reduced_cost`](#method.reduced_cost).
+    /// # Errors
+    /// This method will return an Error if the variable is not registered for the calling [`Model`](struct.model.html),
+    /// or in the cases of [`reduced_cost`](#method.reduced_cost).
+    pub fn try_reduced_cost(&self, req: &Var) -> Result<f64, LinprogError> {
+        match (
+            self.variables[self.try_variable_position(req)?].reduced_cost,
+            self.status,
+        ) {
+            (Some(reduced_cost), _) => Result::Ok(reduced_cost),
+            (None, Some(_)) => Result::Err(LinprogError::NotAvailable("reduced costs")),
+            (None, None) => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns the dual value (shadow price) of a given, registered constraint:
+    /// The change of the optimum per unit increase of the constraint's right side.
+    ///
+    /// Dual values are only available for optimal linear programs, not for models with integer or binary variables.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if the optimization did not yield dual values (see [`status`](#method.status)).
+    /// # Panics
+    /// This method panics if the constraint is not registered for the calling [`Model`](struct.model.html).
+    pub fn dual(&self, req: &Constr) -> Result<f64, LinprogError> {
+        error::registered(self.try_dual(req))
+    }
+
+    /// Returns the dual value of a given constraint, see [`dual`](#method.dual).
+    /// # Errors
+    /// This method will return an Error if the constraint is not registered for the calling [`Model`](struct.model.html),
+    /// or in the cases of [`dual`](#method.dual).
+    pub fn try_dual(&self, req: &Constr) -> Result<f64, LinprogError> {
+        match (
+            self.constraints[self.try_constraint_position(req)?].dual,
+            self.status,
+        ) {
+            (Some(dual), _) => Result::Ok(dual),
+            (None, Some(_)) => Result::Err(LinprogError::NotAvailable("dual values")),
+            (None, None) => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns a Farkas certificate proving the [`Model`](struct.model.html) infeasible: A multiplier `y` for every
+    /// registered constraint `a x (<=, =, >=) b`, non-negative for `<=` and non-positive for `>=` constraints, such that
+    /// the sum of `y b` is less than the minimum of the sum of `y a x` within the variables' bounds.
+    ///
+    /// Certificates are only available for infeasible linear programs, or if the root relaxation of branch-and-bound
+    /// is infeasible.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if no certificate is available.
+    pub fn farkas_certificate(&self) -> Result<Vec<(Constr, f64)>, LinprogError> {
+        match self.status {
+            Option::None => return Result::Err(LinprogError::NotOptimized),
+            Option::Some(SolveStatus::Infeasible) => (),
+            Option::Some(_) => return Result::Err(LinprogError::NotAvailable("certificate")),
+        }
+        self.constraints
+            .iter()
+            .enumerate()
+            .map(|(position, constraint)| match constraint.farkas {
+                Option::Some(y) => Result::Ok((self.constr_handle(position), y)),
+                Option::None => Result::Err(LinprogError::NotAvailable("certificate")),
+            })
+            .collect()
+    }
+
+    /// Returns a ray proving the [`Model`](struct.model.html) unbounded: A direction `r` for every registered variable,
+    /// along which all constraints stay satisfied from a feasible solution, and the objective improves without limit.
+    ///
+    /// Rays are only available for unbounded linear programs, or if a relaxation of branch-and-bound is unbounded.
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if no ray is available.
+    pub fn unbounded_ray(&self) -> Result<Vec<(Var, f64)>, LinprogError> {
+        match self.status {
+            Option::None => return Result::Err(LinprogError::NotOptimized),
+            Option::Some(SolveStatus::Unbounded) => (),
+            Option::Some(_) => return Result::Err(LinprogError::NotAvailable("ray")),
+        }
+        self.variables
+            .iter()
+            .enumerate()
+            .map(|(position, variable)| match variable.ray {
+                Option::Some(r) => Result::Ok((self.var_handle(position), r)),
+                Option::None => Result::Err(LinprogError::NotAvailable("ray")),
+            })
+            .collect()
+    }
+
+    /// Returns the [`SolveStatus`](enum.SolveStatus.html) of the last optimization.
+    /// # Errors
+    /// This method will return an Error if the calling [`Model`](struct.model.html) has not been optimized. See [`optimize`](#method.optimize).
+    pub fn status(&self) -> Result<SolveStatus, LinprogError> {
+        self.status.ok_or(LinprogError::NotOptimized)
+    }
+
     /// Returns the optimal value.
+    ///
+    /// If branch-and-bound stopped early, this is the incumbent's objective value.
+    /// # Errors
+    /// This method will return an Error if the calling [`Model`](struct.model.html) has not been optimized (see [`optimize`](#method.optimize)),
+    /// or if the optimization stopped without a solution (see [`status`](#method.status)).
+    pub fn optimum(&self) -> Result<f64, LinprogError> {
+        match (self.optimum, self.status) {
+            (Option::Some(optimum), _) => Result::Ok(optimum),
+            (Option::None, Option::Some(status)) => Result::Err(LinprogError::no_solution(status)),
+            (Option::None, Option::None) => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns the best bound on the optimal value proven by branch-and-bound.
+    /// For linear programs or completed searches, it equals the [`optimum`](#method.optimum) up to the gap tolerance.
+    /// # Errors
+    /// This method will return an Error if the calling [`Model`](struct.model.html) has not been optimized. See [`optimize`](#method.optimize).
+    pub fn best_bound(&self) -> Result<f64, LinprogError> {
+        self.best_bound.ok_or(LinprogError::NotOptimized)
+    }
+
+    /// Returns the relative gap between the [`optimum`](#method.optimum) and the [`best_bound`](#method.best_bound).
     /// # Errors
     /// This method will return an Error if the calling [`Model`](struct.model.html) has not been optimized. See [`optimize`](#method.optimize).
-    pub fn optimum(&self) -> Result<f64, &'static str> {
-        self.optimum.ok_or_else(|| "Model not optimized")
+    pub fn gap(&self) -> Result<f64, LinprogError> {
+        match (self.optimum, self.best_bound) {
+            (Option::Some(optimum), Option::Some(best_bound)) => {
+                Result::Ok(mip::relative_gap(best_bound, optimum))
+            }
+            _ => Result::Err(LinprogError::NotOptimized),
+        }
+    }
+
+    /// Returns the number of nodes solved by the last branch-and-bound search.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Returns the number of simplex iterations (pivots) of the last optimization.
+    pub fn iteration_count(&self) -> usize {
+        self.iteration_count
+    }
+
+    /// Returns the warnings of the last optimization, e.g. [`BIG_M_WARNING`](constant.BIG_M_WARNING.html) if `M`
+    /// (see [`Initialization::BigM`](enum.Initialization.html#variant.BigM)) looks too small to trust the result.
+    pub fn warnings(&self) -> &[&'static str] {
+        &self.warnings
     }
 }
 
 // I know this part does not look good
 impl fmt::Display for Model {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.optimum {
-            Option::Some(optimum) => {
+        match (self.optimum, self.status) {
+            (Option::Some(optimum), Option::Some(SolveStatus::Optimal)) => {
                 let mut i = 0;
                 writeln!(
                     f,
@@ -301,13 +1094,21 @@ impl fmt::Display for Model {
                     self.variables.iter().fold(String::new(), |acc, variable| {
                         i += 1;
                         acc + "\n\tVariable \""
-                            + &variable.name.as_ref().unwrap_or(&i.to_string())
+                            + variable.name.as_ref().unwrap_or(&i.to_string())
                             + "\": "
                             + &variable.x.unwrap().to_string()
                     })
                 )
             }
-            Option::None => writeln!(f, "\nModel \"{}\" [not optimized]", self.name),
+            (Option::Some(optimum), Option::Some(status)) => writeln!(
+                f,
+                "\nModel \"{}\" [{}]:\n\tOptimum: {}",
+                self.name, status, optimum
+            ),
+            (Option::None, Option::Some(status)) => {
+                writeln!(f, "\nModel \"{}\" [{}]", self.name, status)
+            }
+            (_, Option::None) => writeln!(f, "\nModel \"{}\" [not optimized]", self.name),
         }
     }
 }
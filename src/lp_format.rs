@@ -0,0 +1,765 @@
+//! Reading and writing models in the CPLEX LP format.
+
+use crate::{LinprogError, Model, Objective, Operator, ParseError, Summand, Var, VarType};
+use std::collections::HashMap;
+
+/// The characters names may consist of besides letters and digits. Names start with a letter or one of these, except
+/// for `.`.
+const NAME_CHARACTERS: &str = "!\"#$%&()/,.;?@_`'{}|~";
+
+/// The keywords starting the sections, in lower case.
+const KEYWORDS: [(&str, Section); 19] = [
+    ("maximize", Section::Objective(Objective::Max)),
+    ("maximum", Section::Objective(Objective::Max)),
+    ("max", Section::Objective(Objective::Max)),
+    ("minimize", Section::Objective(Objective::Min)),
+    ("minimum", Section::Objective(Objective::Min)),
+    ("min", Section::Objective(Objective::Min)),
+    ("subject to", Section::Constraints),
+    ("such that", Section::Constraints),
+    ("st", Section::Constraints),
+    ("s.t.", Section::Constraints),
+    ("bounds", Section::Bounds),
+    ("bound", Section::Bounds),
+    ("generals", Section::Generals),
+    ("general", Section::Generals),
+    ("gen", Section::Generals),
+    ("binaries", Section::Binaries),
+    ("binary", Section::Binaries),
+    ("bin", Section::Binaries),
+    ("end", Section::End),
+];
+
+/// The keywords of sections which are not supported, in lower case.
+const UNSUPPORTED_KEYWORDS: [&str; 3] = ["semi", "semis", "sos"];
+
+/// The sections of an LP file.
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Section {
+    Objective(Objective),
+    Constraints,
+    Bounds,
+    Generals,
+    Binaries,
+    End,
+}
+
+/// The kinds of tokens.
+#[derive(PartialEq, Clone, Debug)]
+enum Kind {
+    Name(String),
+    Number(f64),
+    /// `1` for `+`, `-1` for `-`
+    Sign(f64),
+    Comparison(Operator),
+    Colon,
+}
+
+/// A token and the line and column (both starting at 1) it starts at.
+struct Token {
+    line: usize,
+    column: usize,
+    kind: Kind,
+}
+
+/// The tokens of a section.
+struct Block {
+    section: Section,
+    /// The line and column of the section's keyword
+    start: (usize, usize),
+    tokens: Vec<Token>,
+    /// The line and column of the next section's keyword, where missing tokens are reported
+    end: (usize, usize),
+}
+
+/// A variable as read from the sections.
+struct Column {
+    name: String,
+    objective_value: f64,
+    var_type: VarType,
+    lower: f64,
+    upper: f64,
+}
+
+/// The variables in the order of their first appearance.
+#[derive(Default)]
+struct Columns {
+    columns: Vec<Column>,
+    index: HashMap<String, usize>,
+}
+
+/// A constraint as read from the constraints section.
+struct Row {
+    name: Option<String>,
+    entries: Vec<(usize, f64)>,
+    op: Operator,
+    b: f64,
+    /// The range below `b` for constraints with two comparisons
+    range: Option<f64>,
+}
+
+/// Returns whether a character may start a name.
+fn is_name_start(character: char) -> bool {
+    character.is_alphabetic() || (character != '.' && NAME_CHARACTERS.contains(character))
+}
+
+/// Returns whether a character may be part of a name.
+fn is_name_character(character: char) -> bool {
+    character.is_alphanumeric() || NAME_CHARACTERS.contains(character)
+}
+
+/// Returns whether a name stands for infinity.
+fn is_infinity(name: &str) -> bool {
+    name.eq_ignore_ascii_case("inf") || name.eq_ignore_ascii_case("infinity")
+}
+
+/// Splits a line into tokens, up to a comment starting with `\`.
+fn tokenize(text: &str, line: usize) -> Result<Vec<Token>, ParseError> {
+    let characters: Vec<char> = text.chars().collect();
+    let mut tokens = vec![];
+    let mut position = 0;
+    while position < characters.len() {
+        let start = position;
+        let character = characters[position];
+        position += 1;
+        let kind = match character {
+            '\\' => break,
+            _ if character.is_whitespace() => continue,
+            '+' => Kind::Sign(1f64),
+            '-' => Kind::Sign(-1f64),
+            ':' => Kind::Colon,
+            '<' | '>' | '=' => {
+                let next = characters.get(position).cloned();
+                // Comparisons may consist of one character or of two: `<=`, `=<`, `>=` and `=>`
+                let op = match (character, next) {
+                    ('<', _) | ('=', Option::Some('<')) => Operator::Le,
+                    ('>', _) | ('=', Option::Some('>')) => Operator::Ge,
+                    _ => Operator::E,
+                };
+                if (character != '=' && next == Option::Some('='))
+                    || (character == '=' && op != Operator::E)
+                {
+                    position += 1;
+                }
+                Kind::Comparison(op)
+            }
+            _ if character.is_ascii_digit() || character == '.' => {
+                while position < characters.len()
+                    && (characters[position].is_ascii_digit() || characters[position] == '.')
+                {
+                    position += 1;
+                }
+                if let Option::Some('e') | Option::Some('E') = characters.get(position) {
+                    let mut exponent = position + 1;
+                    if let Option::Some('+') | Option::Some('-') = characters.get(exponent) {
+                        exponent += 1;
+                    }
+                    if characters.get(exponent).is_some_and(char::is_ascii_digit) {
+                        position = exponent;
+                        while position < characters.len() && characters[position].is_ascii_digit() {
+                            position += 1;
+                        }
+                    }
+                }
+                let number: String = characters[start..position].iter().collect();
+                Kind::Number(number.parse().map_err(|_| ParseError {
+                    line,
+                    column: start + 1,
+                    message: "Invalid number",
+                })?)
+            }
+            _ if is_name_start(character) => {
+                while position < characters.len() && is_name_character(characters[position]) {
+                    position += 1;
+                }
+                Kind::Name(characters[start..position].iter().collect())
+            }
+            _ => {
+                return Result::Err(ParseError {
+                    line,
+                    column: start + 1,
+                    message: "Unexpected character",
+                })
+            }
+        };
+        tokens.push(Token {
+            line,
+            column: start + 1,
+            kind,
+        });
+    }
+    Result::Ok(tokens)
+}
+
+/// Returns the section a line starts and the number of its keyword's tokens, `None` if the line continues a section.
+/// Names followed by a colon are no keywords, but labels.
+fn section(tokens: &[Token]) -> Result<Option<(Section, usize)>, ParseError> {
+    let word = |index: usize| match tokens.get(index) {
+        Option::Some(Token {
+            kind: Kind::Name(name),
+            ..
+        }) => Option::Some(name.to_lowercase()),
+        _ => Option::None,
+    };
+    let first = match word(0) {
+        Option::Some(first) => first,
+        Option::None => return Result::Ok(Option::None),
+    };
+    if let Option::Some(Token {
+        kind: Kind::Colon, ..
+    }) = tokens.get(1)
+    {
+        return Result::Ok(Option::None);
+    }
+    let two_words = word(1).map(|second| format!("{} {}", first, second));
+    for &(keyword, section) in KEYWORDS.iter() {
+        if two_words.as_deref() == Option::Some(keyword) {
+            return Result::Ok(Option::Some((section, 2)));
+        }
+        if first == keyword {
+            return Result::Ok(Option::Some((section, 1)));
+        }
+    }
+    if UNSUPPORTED_KEYWORDS.contains(&first.as_str()) {
+        return Result::Err(ParseError {
+            line: tokens[0].line,
+            column: tokens[0].column,
+            message: "Unsupported section",
+        });
+    }
+    Result::Ok(Option::None)
+}
+
+impl Columns {
+    /// Returns the index of a variable, adding it if it is new.
+    fn index(&mut self, name: &str) -> usize {
+        if let Option::Some(&index) = self.index.get(name) {
+            return index;
+        }
+        self.index.insert(name.to_string(), self.columns.len());
+        self.columns.push(Column {
+            name: name.to_string(),
+            objective_value: 0f64,
+            var_type: VarType::Continuous,
+            lower: 0f64,
+            upper: 1.0 / 0.0,
+        });
+        self.columns.len() - 1
+    }
+
+    /// Bounds a variable by `x op value`.
+    fn bound(&mut self, index: usize, op: Operator, value: f64) {
+        let column = &mut self.columns[index];
+        match op {
+            Operator::Le => column.upper = value,
+            Operator::Ge => column.lower = value,
+            Operator::E => {
+                column.lower = value;
+                column.upper = value;
+            }
+        }
+    }
+}
+
+/// Reads the tokens of a section.
+struct Parser<'a> {
+    block: &'a Block,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self, ahead: usize) -> Option<&'a Kind> {
+        self.block
+            .tokens
+            .get(self.position + ahead)
+            .map(|token| &token.kind)
+    }
+
+    fn at_end(&self) -> bool {
+        self.position >= self.block.tokens.len()
+    }
+
+    /// Returns an error at the token at a position, or at the section's end.
+    fn error_at(&self, position: usize, message: &'static str) -> ParseError {
+        let (line, column) = match self.block.tokens.get(position) {
+            Option::Some(token) => (token.line, token.column),
+            Option::None => self.block.end,
+        };
+        ParseError {
+            line,
+            column,
+            message,
+        }
+    }
+
+    fn error(&self, message: &'static str) -> ParseError {
+        self.error_at(self.position, message)
+    }
+
+    /// Reads a name followed by a colon, if there is one.
+    fn label(&mut self) -> Option<String> {
+        match (self.peek(0), self.peek(1)) {
+            (Option::Some(Kind::Name(name)), Option::Some(Kind::Colon)) => {
+                self.position += 2;
+                Option::Some(name.clone())
+            }
+            _ => Option::None,
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Operator, ParseError> {
+        match self.peek(0) {
+            Option::Some(Kind::Comparison(op)) => {
+                self.position += 1;
+                Result::Ok(*op)
+            }
+            _ => Result::Err(self.error("Expected a comparison")),
+        }
+    }
+
+    fn variable(&mut self) -> Result<&'a str, ParseError> {
+        match self.peek(0) {
+            Option::Some(Kind::Name(name)) if !is_infinity(name) => {
+                self.position += 1;
+                Result::Ok(name)
+            }
+            _ => Result::Err(self.error("Expected a variable")),
+        }
+    }
+
+    /// Reads a number with an optional sign, or infinity if allowed.
+    fn value(&mut self, infinite: bool) -> Result<f64, ParseError> {
+        let sign = match self.peek(0) {
+            Option::Some(Kind::Sign(sign)) => {
+                self.position += 1;
+                *sign
+            }
+            _ => 1f64,
+        };
+        match self.peek(0) {
+            Option::Some(Kind::Number(value)) => {
+                self.position += 1;
+                Result::Ok(sign * value)
+            }
+            Option::Some(Kind::Name(name)) if infinite && is_infinity(name) => {
+                self.position += 1;
+                Result::Ok(sign * (1.0 / 0.0))
+            }
+            _ => Result::Err(self.error("Expected a number")),
+        }
+    }
+
+    /// Returns whether the next tokens are a value followed by a comparison, as in `-inf <= x` or `2 <= x + y`.
+    fn value_first(&self) -> bool {
+        let offset = match self.peek(0) {
+            Option::Some(Kind::Sign(_)) => 1,
+            _ => 0,
+        };
+        let value = match self.peek(offset) {
+            Option::Some(Kind::Number(_)) => true,
+            Option::Some(Kind::Name(name)) => is_infinity(name),
+            _ => false,
+        };
+        value && matches!(self.peek(offset + 1), Option::Some(Kind::Comparison(_)))
+    }
+
+    /// Reads a sum of terms `+ 2 x` as `(variable, coefficient)` pairs. The first term's sign is optional, the
+    /// coefficients are `1` if left out.
+    fn expression(&mut self, columns: &mut Columns) -> Result<Vec<(usize, f64)>, ParseError> {
+        let mut terms = vec![];
+        loop {
+            let sign = match self.peek(0) {
+                Option::Some(Kind::Sign(sign)) => {
+                    self.position += 1;
+                    *sign
+                }
+                Option::Some(Kind::Number(_)) | Option::Some(Kind::Name(_)) if terms.is_empty() => {
+                    1f64
+                }
+                _ => return Result::Ok(terms),
+            };
+            let coefficient = match self.peek(0) {
+                Option::Some(Kind::Number(coefficient)) => {
+                    self.position += 1;
+                    *coefficient
+                }
+                _ => 1f64,
+            };
+            let variable = self.variable()?;
+            terms.push((columns.index(variable), sign * coefficient));
+        }
+    }
+}
+
+/// Writes a number, infinite ones as `inf` and `-inf`.
+fn write_number(value: f64) -> String {
+    format!("{:?}", value)
+}
+
+/// Writes a sum of terms, leaving out coefficients of `1`.
+fn write_sum(terms: &[(f64, &str)]) -> String {
+    let mut sum = String::new();
+    for (index, &(value, name)) in terms.iter().enumerate() {
+        match (index, value < 0f64) {
+            (0, false) => (),
+            (0, true) => sum.push_str("- "),
+            (_, false) => sum.push_str(" + "),
+            (_, true) => sum.push_str(" - "),
+        }
+        if value.abs() != 1f64 {
+            sum.push_str(&write_number(value.abs()));
+            sum.push(' ');
+        }
+        sum.push_str(name);
+    }
+    sum
+}
+
+/// Returns an Error if a name can not be written in the LP format.
+fn check_name(name: &str) -> Result<(), &'static str> {
+    let mut characters = name.chars();
+    let lower_case = name.to_lowercase();
+    match characters.next() {
+        Option::None => Result::Err("Empty name"),
+        Option::Some(first) if !is_name_start(first) || !characters.all(is_name_character) => {
+            Result::Err("Name with characters not allowed in the LP format")
+        }
+        _ if is_infinity(name)
+            || lower_case == "free"
+            || UNSUPPORTED_KEYWORDS.contains(&lower_case.as_str())
+            || KEYWORDS.iter().any(|&(keyword, _)| keyword == lower_case) =>
+        {
+            Result::Err("Name is a keyword of the LP format")
+        }
+        _ => Result::Ok(()),
+    }
+}
+
+impl Model {
+    /// Reads a [`Model`](struct.model.html) from the text of a file in the CPLEX LP format, with an objective
+    /// section (`Maximize` or `Minimize`) and the sections `Subject To`, `Bounds`, `Generals` and `Binaries`,
+    /// followed by `End`.
+    ///
+    /// Variables are registered in the order they first appear in, with a lower bound of `0` unless the bounds say
+    /// otherwise. Constraints may be labeled with a name and a colon, e.g. `c1: x + 2 y <= 4`. Constraints with two
+    /// comparisons, e.g. `1 <= x + y <= 3`, only bound `a x` to the interval. A comment `\Problem name: ...` names the
+    /// model.
+    /// # Errors
+    /// This method will return an Error at the line and column of the first token which can not be read, e.g. an
+    /// invalid number, a missing comparison, or a constant in the objective.
+    pub fn from_lp(lp: &str) -> Result<Model, ParseError> {
+        let mut name = String::new();
+        let mut blocks: Vec<Block> = vec![];
+        let mut ended = false;
+        for (line, text) in lp.lines().enumerate() {
+            let line = line + 1;
+            if let Option::Some(problem) = text.trim_start().strip_prefix("\\Problem name:") {
+                name = problem.trim().to_string();
+                continue;
+            }
+            let mut tokens = tokenize(text, line)?;
+            if let Option::Some((section, length)) = section(&tokens)? {
+                let start = (line, tokens[0].column);
+                if let Option::Some(block) = blocks.last_mut() {
+                    block.end = start;
+                }
+                if section == Section::End {
+                    ended = true;
+                    break;
+                }
+                tokens.drain(..length);
+                blocks.push(Block {
+                    section,
+                    start,
+                    tokens: vec![],
+                    end: (line + 1, 1),
+                });
+            }
+            match blocks.last_mut() {
+                Option::Some(block) => block.tokens.extend(tokens),
+                Option::None if !tokens.is_empty() => {
+                    return Result::Err(ParseError {
+                        line,
+                        column: tokens[0].column,
+                        message: "Data outside of a section",
+                    })
+                }
+                Option::None => (),
+            }
+        }
+        if !ended {
+            return Result::Err(ParseError {
+                line: lp.lines().count() + 1,
+                column: 1,
+                message: "Missing End",
+            });
+        }
+        let mut objective: Option<Objective> = Option::None;
+        let mut columns = Columns::default();
+        let mut rows: Vec<Row> = vec![];
+        for block in blocks.iter() {
+            let mut parser = Parser { block, position: 0 };
+            match block.section {
+                Section::Objective(sense) => {
+                    if objective.is_some() {
+                        return Result::Err(ParseError {
+                            line: block.start.0,
+                            column: block.start.1,
+                            message: "Objective declared twice",
+                        });
+                    }
+                    objective = Option::Some(sense);
+                    parser.label();
+                    for (column, value) in parser.expression(&mut columns)? {
+                        columns.columns[column].objective_value += value;
+                    }
+                    if !parser.at_end() {
+                        return Result::Err(parser.error("Expected + or -"));
+                    }
+                }
+                Section::Constraints => {
+                    while !parser.at_end() {
+                        let name = parser.label();
+                        let bound = if parser.value_first() {
+                            Option::Some((parser.value(false)?, parser.comparison()?))
+                        } else {
+                            Option::None
+                        };
+                        let entries = parser.expression(&mut columns)?;
+                        let position = parser.position;
+                        let op = parser.comparison()?;
+                        let b = parser.value(false)?;
+                        // A range `[lower, upper]` is the range `upper - lower` below the upper bound
+                        let (b, range) = match (bound, op) {
+                            (Option::None, _) => (b, Option::None),
+                            (Option::Some((lower, Operator::Le)), Operator::Le) => {
+                                (b, Option::Some(b - lower))
+                            }
+                            (Option::Some((upper, Operator::Ge)), Operator::Ge) => {
+                                (upper, Option::Some(upper - b))
+                            }
+                            _ => {
+                                return Result::Err(parser.error_at(
+                                    position,
+                                    "Expected two comparisons of the same direction",
+                                ))
+                            }
+                        };
+                        if range.is_some_and(|range| range < 0f64) {
+                            return Result::Err(parser.error_at(position, "Empty range"));
+                        }
+                        rows.push(Row {
+                            name,
+                            entries,
+                            op: if range.is_some() { Operator::Le } else { op },
+                            b,
+                            range,
+                        });
+                    }
+                }
+                Section::Bounds => {
+                    while !parser.at_end() {
+                        match (parser.peek(0), parser.peek(1)) {
+                            (Option::Some(Kind::Name(name)), Option::Some(Kind::Name(free)))
+                                if !is_infinity(name) && free.eq_ignore_ascii_case("free") =>
+                            {
+                                let column = columns.index(name);
+                                columns.bound(column, Operator::Ge, -1.0 / 0.0);
+                                columns.bound(column, Operator::Le, 1.0 / 0.0);
+                                parser.position += 2;
+                            }
+                            _ if parser.value_first() => {
+                                let value = parser.value(true)?;
+                                let op = parser.comparison()?;
+                                let column = columns.index(parser.variable()?);
+                                // `value <= x` is a lower bound, `value >= x` an upper one
+                                let op = match op {
+                                    Operator::Le => Operator::Ge,
+                                    Operator::Ge => Operator::Le,
+                                    Operator::E => Operator::E,
+                                };
+                                columns.bound(column, op, value);
+                                if let Option::Some(Kind::Comparison(_)) = parser.peek(0) {
+                                    let op = parser.comparison()?;
+                                    columns.bound(column, op, parser.value(true)?);
+                                }
+                            }
+                            _ => {
+                                let column = columns.index(parser.variable()?);
+                                let op = parser.comparison()?;
+                                columns.bound(column, op, parser.value(true)?);
+                            }
+                        }
+                    }
+                }
+                Section::Generals | Section::Binaries => {
+                    while !parser.at_end() {
+                        let column = columns.index(parser.variable()?);
+                        columns.columns[column].var_type = match block.section {
+                            Section::Generals => VarType::Integer,
+                            _ => VarType::Binary,
+                        };
+                    }
+                }
+                Section::End => (),
+            }
+        }
+        let mut model = Model::new(&name, objective.unwrap_or(Objective::Min));
+        let vars: Vec<Var> = columns
+            .columns
+            .iter()
+            .map(|column| model.reg_var_with_name(column.objective_value, &column.name))
+            .collect();
+        for (column, var) in columns.columns.iter().zip(vars.iter()) {
+            if column.var_type != VarType::Binary {
+                model.set_var_bounds(var, column.lower, column.upper);
+            }
+            model.set_var_type(var, column.var_type);
+        }
+        for row in rows {
+            let sum = row
+                .entries
+                .iter()
+                .map(|&(column, value)| Summand(value, &vars[column]))
+                .collect();
+            model
+                .reg_constr_overload(sum, row.op, row.b, row.name)
+                .unwrap();
+            if let Option::Some(range) = row.range {
+                model.constraints.last_mut().unwrap().range = range;
+            }
+        }
+        Result::Ok(model)
+    }
+
+    /// Writes the [`Model`](struct.model.html) as the text of a file in the CPLEX LP format, readable by
+    /// [`from_lp`](#method.from_lp).
+    ///
+    /// Unnamed variables are called `C1`, `C2`, ..., unnamed constraints `R1`, `R2`, ... by their position. Every
+    /// variable appears in the objective, with a coefficient of `0` if need be, so they are read in the same order.
+    /// # Errors
+    /// This method will return an Error if a name can not be written in the LP format: An empty name, a name with
+    /// characters other than letters, digits and ``!"#$%&()/,.;?@_`'{}|~``, starting with a digit or `.`, or a name
+    /// which is a keyword, e.g. `free` or `end`.
+    pub fn write_lp(&self) -> Result<String, LinprogError> {
+        let variable_names: Vec<String> = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(index, variable)| {
+                variable
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("C{}", index + 1))
+            })
+            .collect();
+        let constraint_names: Vec<String> = self
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(index, constraint)| {
+                constraint
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("R{}", index + 1))
+            })
+            .collect();
+        for name in variable_names.iter().chain(constraint_names.iter()) {
+            check_name(name).map_err(LinprogError::InvalidName)?;
+        }
+        let mut lp = format!("\\Problem name: {}\n\n", self.name);
+        lp.push_str(match self.objective {
+            Objective::Max => "Maximize\n",
+            Objective::Min => "Minimize\n",
+        });
+        let objective: Vec<(f64, &str)> = self
+            .variables
+            .iter()
+            .zip(variable_names.iter())
+            .map(|(variable, name)| (variable.objective_value, name.as_str()))
+            .collect();
+        lp.push_str(&format!(" obj: {}\n", write_sum(&objective)));
+        lp.push_str("Subject To\n");
+        for (constraint, name) in self.constraints.iter().zip(constraint_names.iter()) {
+            let sum: Vec<(f64, &str)> = self
+                .matrix
+                .row(constraint.row)
+                .map(|(column, value)| (constraint.factor * value, variable_names[column].as_str()))
+                .collect();
+            let sum = write_sum(&sum);
+            let b = constraint.factor * self.rhs[constraint.row];
+            let line = if constraint.range == 0f64 {
+                format!("{} = {}", sum, write_number(b))
+            } else if constraint.range.is_infinite() && constraint.factor < 0f64 {
+                format!("{} >= {}", sum, write_number(b))
+            } else if constraint.range.is_infinite() {
+                format!("{} <= {}", sum, write_number(b))
+            } else {
+                // The range lies below the row's right side, which is `-b` for rows of `>=` constraints
+                let (lower, upper) = if constraint.factor < 0f64 {
+                    (b, b + constraint.range)
+                } else {
+                    (b - constraint.range, b)
+                };
+                format!(
+                    "{} <= {} <= {}",
+                    write_number(lower),
+                    sum,
+                    write_number(upper)
+                )
+            };
+            lp.push_str(&format!(" {}: {}\n", name, line));
+        }
+        let mut bounds = String::new();
+        for (variable, name) in self.variables.iter().zip(variable_names.iter()) {
+            let (lower, upper) = (variable.lower, variable.upper);
+            let bound = if variable.var_type == VarType::Binary {
+                continue;
+            } else if lower == upper {
+                format!("{} = {}", name, write_number(lower))
+            } else if lower == -1.0 / 0.0 && upper == 1.0 / 0.0 {
+                format!("{} free", name)
+            } else if upper == 1.0 / 0.0 && lower != 0f64 {
+                format!("{} >= {}", name, write_number(lower))
+            } else if upper == 1.0 / 0.0 {
+                continue;
+            } else if lower == 0f64 {
+                format!("{} <= {}", name, write_number(upper))
+            } else {
+                format!(
+                    "{} <= {} <= {}",
+                    write_number(lower),
+                    name,
+                    write_number(upper)
+                )
+            };
+            bounds.push_str(&format!(" {}\n", bound));
+        }
+        if !bounds.is_empty() {
+            lp.push_str("Bounds\n");
+            lp.push_str(&bounds);
+        }
+        for (var_type, section) in [
+            (VarType::Integer, "Generals\n"),
+            (VarType::Binary, "Binaries\n"),
+        ]
+        .iter()
+        {
+            let names: Vec<&String> = self
+                .variables
+                .iter()
+                .zip(variable_names.iter())
+                .filter(|(variable, _)| variable.var_type == *var_type)
+                .map(|(_, name)| name)
+                .collect();
+            if !names.is_empty() {
+                lp.push_str(section);
+                for name in names {
+                    lp.push_str(&format!(" {}\n", name));
+                }
+            }
+        }
+        lp.push_str("End\n");
+        Result::Ok(lp)
+    }
+}
@@ -0,0 +1,234 @@
+//! Branch-and-bound for models with integer or binary variables.
+
+use crate::solver::{self, BasisStatus};
+use crate::{Model, Objective, SolveStatus, SolverOptions, VarType};
+use std::collections::HashMap;
+
+/// Distance to the nearest integer up to which a value is considered integral.
+const INTEGRALITY_TOLERANCE: f64 = 1e-6;
+
+/// A subproblem of the search tree.
+struct Node {
+    /// Bounds tightening the variables' own, given as `(variable, lower, upper)`.
+    bounds: Vec<(usize, f64, f64)>,
+    /// The parent's relaxed optimum (maximizing), an upper bound for this node.
+    bound: f64,
+    /// The parent's optimal basis, dual feasible for this node.
+    basis: Option<Vec<BasisStatus>>,
+}
+
+/// Returns the relative gap between a bound and a (maximizing) objective value.
+pub fn relative_gap(bound: f64, value: f64) -> f64 {
+    if bound == value {
+        0f64
+    } else if value.is_infinite() || bound.is_infinite() {
+        1.0 / 0.0
+    } else {
+        (bound - value).abs() / value.abs().max(1e-10)
+    }
+}
+
+impl Model {
+    /// Searches an optimal solution respecting the variables' [`VarType`](enum.VarType.html)s.
+    ///
+    /// The search is depth first, exploring the child closer to the relaxed value first.
+    /// All values are handled maximizing, as in the tableau.
+    pub(crate) fn branch_and_bound(&mut self) {
+        let variable_count = self.variables.len();
+        let root_bounds: Vec<(usize, f64, f64)> = self
+            .variables
+            .iter()
+            .enumerate()
+            .filter(|(_, variable)| variable.var_type == VarType::Binary)
+            .map(|(index, _)| (index, 0f64, 1f64))
+            .collect();
+        let mut open: Vec<Node> = vec![Node {
+            bounds: root_bounds,
+            bound: 1.0 / 0.0,
+            basis: Option::None,
+        }];
+        let mut incumbent: Option<(HashMap<usize, f64>, f64)> = Option::None;
+        let mut status = SolveStatus::Optimal;
+        let mut farkas: Option<Vec<f64>> = Option::None;
+        // The largest bound of the nodes pruned for the gap tolerance, not proven by the incumbent
+        let mut pruned_bound = -1.0 / 0.0;
+        // Ranges of the relaxations are meaningless for the integer solution
+        let options = SolverOptions {
+            sensitivity: false,
+            ..self.options.clone()
+        };
+        self.node_count = 0;
+        self.iteration_count = 0;
+        while let Option::Some(node) = open.pop() {
+            if let Option::Some((_, value)) = incumbent {
+                if node.bound <= value {
+                    continue;
+                }
+                if relative_gap(node.bound, value) <= self.gap_tolerance {
+                    pruned_bound = f64::max(pruned_bound, node.bound);
+                    continue;
+                }
+            }
+            if let Option::Some(node_limit) = self.node_limit {
+                if self.node_count >= node_limit {
+                    open.push(node);
+                    status = SolveStatus::NodeLimit;
+                    break;
+                }
+            }
+            self.node_count += 1;
+            // The parent's basis only violates the new bound, the dual simplex algorithm repairs it
+            let mut problem = self.relaxation(&node.bounds);
+            problem.basis = node.basis.clone();
+            let solution = solver::solve(&problem, &options);
+            self.iteration_count += solution.iterations;
+            for warning in solution.warnings {
+                if !self.warnings.contains(&warning) {
+                    self.warnings.push(warning);
+                }
+            }
+            let value = solution.value;
+            let basis = solution.basis;
+            let solution = match solution.status {
+                SolveStatus::Optimal => solution.x.unwrap(),
+                SolveStatus::Infeasible => {
+                    if self.node_count == 1 {
+                        // The root relaxation proves the model infeasible
+                        farkas = solution.farkas;
+                    }
+                    continue;
+                }
+                SolveStatus::Unbounded => {
+                    // The relaxation's ray is one of the model's if the model has an integer solution at all
+                    let status = match incumbent {
+                        Option::Some(_) => SolveStatus::Optimal,
+                        Option::None => self.search_integer_solution(),
+                    };
+                    let (status, value, bound) = match status {
+                        SolveStatus::Optimal => (SolveStatus::Unbounded, value, value),
+                        SolveStatus::Infeasible => (status, -1.0 / 0.0, -1.0 / 0.0),
+                        status => (status, -1.0 / 0.0, 1.0 / 0.0),
+                    };
+                    self.store_solution(status, Option::None, Option::Some(value));
+                    if status == SolveStatus::Unbounded {
+                        self.store_certificates(Option::None, solution.ray);
+                    }
+                    self.best_bound = match self.objective {
+                        Objective::Max => Option::Some(bound),
+                        Objective::Min => Option::Some(-bound),
+                    };
+                    return;
+                }
+                relaxation_status => {
+                    open.push(node);
+                    status = relaxation_status;
+                    break;
+                }
+            };
+            if let Option::Some((_, incumbent_value)) = incumbent {
+                if value <= incumbent_value {
+                    continue;
+                }
+            }
+            let mut branching: Option<(usize, f64, f64)> = Option::None;
+            for (variable, x) in (0..variable_count).map(|variable| (variable, solution[&variable]))
+            {
+                if self.variables[variable].var_type == VarType::Continuous {
+                    continue;
+                }
+                let distance = (x - x.round()).abs();
+                let further = match branching {
+                    Option::Some((_, _, max_distance)) => distance > max_distance,
+                    Option::None => true,
+                };
+                if distance > INTEGRALITY_TOLERANCE && further {
+                    branching = Option::Some((variable, x, distance));
+                }
+            }
+            match branching {
+                Option::Some((variable, x, _)) => {
+                    let mut down = node.bounds.clone();
+                    down.push((variable, -1.0 / 0.0, x.floor()));
+                    let mut up = node.bounds;
+                    up.push((variable, x.ceil(), 1.0 / 0.0));
+                    let (first, second) = if x - x.floor() < 0.5 {
+                        (down, up)
+                    } else {
+                        (up, down)
+                    };
+                    open.push(Node {
+                        bounds: second,
+                        bound: value,
+                        basis: basis.clone(),
+                    });
+                    open.push(Node {
+                        bounds: first,
+                        bound: value,
+                        basis,
+                    });
+                }
+                Option::None => {
+                    let mut solution = solution;
+                    for (&variable, x) in solution.iter_mut() {
+                        if self.variables[variable].var_type != VarType::Continuous {
+                            *x = x.round();
+                        }
+                    }
+                    // The objective value of the rounded solution, maximizing
+                    let sign = match self.objective {
+                        Objective::Max => 1f64,
+                        Objective::Min => -1f64,
+                    };
+                    let value = (0..variable_count)
+                        .map(|variable| {
+                            sign * self.variables[variable].objective_value * solution[&variable]
+                        })
+                        .sum();
+                    incumbent = Option::Some((solution, value));
+                }
+            }
+        }
+        let incumbent_value = incumbent.as_ref().map_or(-1.0 / 0.0, |&(_, value)| value);
+        let best_bound = open
+            .iter()
+            .map(|node| node.bound)
+            .fold(incumbent_value.max(pruned_bound), f64::max);
+        match incumbent {
+            Option::Some((solution, value)) => {
+                self.store_solution(status, Option::Some(solution), Option::Some(value))
+            }
+            Option::None if open.is_empty() => {
+                self.store_solution(
+                    SolveStatus::Infeasible,
+                    Option::None,
+                    Option::Some(-1.0 / 0.0),
+                );
+                self.store_certificates(farkas, Option::None);
+            }
+            Option::None => self.store_solution(status, Option::None, Option::Some(-1.0 / 0.0)),
+        }
+        self.best_bound = match self.objective {
+            Objective::Max => Option::Some(best_bound + 0f64),
+            Objective::Min => Option::Some(-best_bound + 0f64),
+        };
+    }
+
+    /// Searches any integer solution by branch-and-bound without objective, returning the search's status:
+    /// `Optimal` if there is one. The solution itself is discarded, the nodes and iterations are counted.
+    fn search_integer_solution(&mut self) -> SolveStatus {
+        let objective_values: Vec<f64> = self
+            .variables
+            .iter_mut()
+            .map(|variable| std::mem::replace(&mut variable.objective_value, 0f64))
+            .collect();
+        let (node_count, iteration_count) = (self.node_count, self.iteration_count);
+        self.branch_and_bound();
+        for (variable, objective_value) in self.variables.iter_mut().zip(objective_values) {
+            variable.objective_value = objective_value;
+            variable.x = Option::None;
+        }
+        self.node_count += node_count;
+        self.iteration_count += iteration_count;
+        self.status.unwrap()
+    }
+}
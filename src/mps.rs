@@ -0,0 +1,593 @@
+//! Reading and writing models in the MPS format.
+
+use crate::{LinprogError, Model, Objective, Operator, ParseError, Summand, Var, VarType};
+use std::collections::HashMap;
+
+/// The layout of the fields in the lines of an MPS file.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum MpsFormat {
+    /// Fields at fixed columns (2-3, 5-12, 15-22, 25-36, 40-47 and 50-61): Names have up to 8 characters and may
+    /// contain spaces.
+    Fixed,
+    /// Fields separated by whitespace: Names have any length, but no spaces.
+    Free,
+}
+
+/// The first and the last column (starting at 0, exclusive) of the fields in the fixed format.
+const FIXED_FIELDS: [(usize, usize); 6] = [(1, 3), (4, 12), (14, 22), (24, 36), (39, 47), (49, 61)];
+
+/// The maximal length of a name in the fixed format.
+const FIXED_NAME_LENGTH: usize = 8;
+
+/// The maximal length of a number in the fixed format.
+const FIXED_NUMBER_LENGTH: usize = 12;
+
+/// The sections of an MPS file, in their order.
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Section {
+    Name,
+    ObjSense,
+    Rows,
+    Columns,
+    Rhs,
+    Ranges,
+    Bounds,
+}
+
+/// A variable as read from the COLUMNS and BOUNDS sections.
+struct Column {
+    name: String,
+    objective_value: f64,
+    /// The coefficients as `(row, value)` pairs
+    entries: Vec<(usize, f64)>,
+    var_type: VarType,
+    /// The lower bound, `None` if not given
+    lower: Option<f64>,
+    upper: f64,
+}
+
+/// A field of a line: The column it starts at (starting at 1) and its content.
+type Field = (usize, String);
+
+/// Splits a line into its non-empty fields.
+fn fields(line: &str, format: MpsFormat) -> Vec<Field> {
+    let characters: Vec<char> = line.chars().collect();
+    match format {
+        MpsFormat::Fixed => FIXED_FIELDS
+            .iter()
+            .filter(|&&(start, _)| start < characters.len())
+            .map(|&(start, end)| {
+                let field: String = characters[start..end.min(characters.len())]
+                    .iter()
+                    .collect();
+                (start + 1, field.trim().to_string())
+            })
+            .filter(|(_, field)| !field.is_empty())
+            .collect(),
+        MpsFormat::Free => {
+            let mut fields: Vec<Field> = vec![];
+            for (column, &character) in characters.iter().enumerate() {
+                if character.is_whitespace() {
+                    continue;
+                }
+                match fields.last_mut() {
+                    Option::Some((start, field))
+                        if *start + field.chars().count() == column + 1 =>
+                    {
+                        field.push(character)
+                    }
+                    _ => fields.push((column + 1, character.to_string())),
+                }
+            }
+            fields
+        }
+    }
+}
+
+/// Reads an objective sense.
+fn sense(field: &Field, line: usize) -> Result<Objective, ParseError> {
+    match field.1.as_str() {
+        "MAX" | "MAXIMIZE" => Result::Ok(Objective::Max),
+        "MIN" | "MINIMIZE" => Result::Ok(Objective::Min),
+        _ => Result::Err(ParseError {
+            line,
+            column: field.0,
+            message: "Unknown objective sense",
+        }),
+    }
+}
+
+/// Reads a number.
+fn number(field: &Field, line: usize) -> Result<f64, ParseError> {
+    field.1.parse().map_err(|_| ParseError {
+        line,
+        column: field.0,
+        message: "Invalid number",
+    })
+}
+
+/// Writes a number, in the fixed format shortened to the field's length.
+fn write_number(value: f64, format: MpsFormat) -> String {
+    let number = format!("{:?}", value);
+    if format == MpsFormat::Free || number.len() <= FIXED_NUMBER_LENGTH {
+        return number;
+    }
+    (0..FIXED_NUMBER_LENGTH)
+        .rev()
+        .map(|precision| format!("{:.*e}", precision, value))
+        .find(|number| number.len() <= FIXED_NUMBER_LENGTH)
+        .unwrap()
+}
+
+/// Writes a line of fields, empty fields being left out.
+fn write_line(fields: [&str; 6], format: MpsFormat) -> String {
+    match format {
+        MpsFormat::Fixed => {
+            let mut line = String::new();
+            for (field, &(start, _)) in fields.iter().zip(FIXED_FIELDS.iter()) {
+                if field.is_empty() {
+                    continue;
+                }
+                let length = line.chars().count();
+                line.push_str(&" ".repeat(start.saturating_sub(length).max(1)));
+                line.push_str(field);
+            }
+            line + "\n"
+        }
+        MpsFormat::Free => {
+            let fields: Vec<&str> = fields
+                .iter()
+                .cloned()
+                .filter(|field| !field.is_empty())
+                .collect();
+            format!(" {}\n", fields.join(" "))
+        }
+    }
+}
+
+/// Returns an Error if a name can not be written in the given format.
+fn check_name(name: &str, format: MpsFormat) -> Result<(), &'static str> {
+    match format {
+        _ if name.is_empty() => Result::Err("Empty name"),
+        MpsFormat::Fixed if name.chars().count() > FIXED_NAME_LENGTH => {
+            Result::Err("Name too long for the fixed MPS format")
+        }
+        MpsFormat::Free if name.contains(char::is_whitespace) => {
+            Result::Err("Name with whitespace in the free MPS format")
+        }
+        _ => Result::Ok(()),
+    }
+}
+
+impl Model {
+    /// Reads a [`Model`](struct.model.html) from the text of an MPS file with the sections NAME, OBJSENSE (optional),
+    /// ROWS, COLUMNS, RHS, RANGES and BOUNDS, in the given [`MpsFormat`](enum.MpsFormat.html).
+    ///
+    /// The first `N` row is the objective, further ones are ignored. The objective is minimized unless OBJSENSE says
+    /// otherwise. Variables between `'INTORG'` and `'INTEND'` markers are integer. Constraints and variables get the
+    /// names of their rows and columns. Constraints with a range only bound `a x` to the interval given by the range,
+    /// as in the MPS format.
+    /// # Errors
+    /// This method will return an Error at the line and column of the first field which can not be read, e.g. an
+    /// unknown row or column, an invalid number, or a right side of the objective (a constant).
+    pub fn from_mps(mps: &str, format: MpsFormat) -> Result<Model, ParseError> {
+        let mut name = String::new();
+        let mut objective = Objective::Min;
+        let mut objective_row: Option<String> = Option::None;
+        let mut free_rows: Vec<String> = vec![];
+        let mut rows: Vec<(String, Operator)> = vec![];
+        let mut row_index: HashMap<String, usize> = HashMap::new();
+        let mut columns: Vec<Column> = vec![];
+        let mut column_index: HashMap<String, usize> = HashMap::new();
+        let mut rhs: Vec<f64> = vec![];
+        let mut ranges: Vec<Option<f64>> = vec![];
+        let mut integer = false;
+        let mut section: Option<Section> = Option::None;
+        let mut ended = false;
+        for (line, text) in mps.lines().enumerate() {
+            let line = line + 1;
+            let error = |column: usize, message: &'static str| ParseError {
+                line,
+                column,
+                message,
+            };
+            if text.trim().is_empty() || text.starts_with('*') {
+                continue;
+            }
+            if !text.starts_with(char::is_whitespace) {
+                // A section header, maybe followed by a value
+                let header = fields(text, MpsFormat::Free);
+                let keyword = header[0].1.as_str();
+                section = Option::Some(match keyword {
+                    "NAME" => {
+                        name = text[4..].trim().to_string();
+                        Section::Name
+                    }
+                    "OBJSENSE" => {
+                        if let Option::Some(value) = header.get(1) {
+                            objective = sense(value, line)?;
+                        }
+                        Section::ObjSense
+                    }
+                    "ROWS" => Section::Rows,
+                    "COLUMNS" => Section::Columns,
+                    "RHS" => Section::Rhs,
+                    "RANGES" => Section::Ranges,
+                    "BOUNDS" => Section::Bounds,
+                    "ENDATA" => {
+                        ended = true;
+                        break;
+                    }
+                    _ => return Result::Err(error(1, "Unknown section")),
+                });
+                continue;
+            }
+            let fields = fields(text, format);
+            if fields.is_empty() {
+                // The text is in the gaps between the fixed fields
+                let column = text.chars().position(|c| !c.is_whitespace()).unwrap() + 1;
+                return Result::Err(error(column, "Text outside of the fields"));
+            }
+            // Reads `(row, value)` pairs, following a name if their number is odd
+            let pairs = |fields: &[Field]| -> Result<Vec<(usize, f64, String)>, ParseError> {
+                let fields = &fields[fields.len() % 2..];
+                if fields.is_empty() {
+                    return Result::Err(error(text.chars().count() + 1, "Missing value"));
+                }
+                fields
+                    .chunks(2)
+                    .map(|pair| Result::Ok((pair[0].0, number(&pair[1], line)?, pair[0].1.clone())))
+                    .collect()
+            };
+            let row = |column: usize, row: &str| -> Result<Option<usize>, ParseError> {
+                match row_index.get(row) {
+                    Option::Some(&row) => Result::Ok(Option::Some(row)),
+                    Option::None if free_rows.iter().any(|free| free == row) => {
+                        Result::Ok(Option::None)
+                    }
+                    Option::None => Result::Err(error(column, "Unknown row")),
+                }
+            };
+            match section {
+                Option::Some(Section::ObjSense) => objective = sense(&fields[0], line)?,
+                Option::Some(Section::Rows) => {
+                    if fields.len() != 2 {
+                        return Result::Err(error(fields[0].0, "Expected a row type and a name"));
+                    }
+                    let (column, row_name) = fields[1].clone();
+                    if row_index.contains_key(&row_name)
+                        || objective_row.as_ref() == Option::Some(&row_name)
+                    {
+                        return Result::Err(error(column, "Row declared twice"));
+                    }
+                    let op = match fields[0].1.as_str() {
+                        "N" if objective_row.is_none() => {
+                            objective_row = Option::Some(row_name);
+                            continue;
+                        }
+                        "N" => {
+                            free_rows.push(row_name);
+                            continue;
+                        }
+                        "L" => Operator::Le,
+                        "G" => Operator::Ge,
+                        "E" => Operator::E,
+                        _ => return Result::Err(error(fields[0].0, "Unknown row type")),
+                    };
+                    row_index.insert(row_name.clone(), rows.len());
+                    rows.push((row_name, op));
+                    rhs.push(0f64);
+                    ranges.push(Option::None);
+                }
+                Option::Some(Section::Columns) => {
+                    if fields.len() >= 3 && fields[1].1 == "'MARKER'" {
+                        integer = match fields[2].1.as_str() {
+                            "'INTORG'" => true,
+                            "'INTEND'" => false,
+                            _ => return Result::Err(error(fields[2].0, "Unknown marker")),
+                        };
+                        continue;
+                    }
+                    if fields.len() % 2 != 1 {
+                        return Result::Err(error(
+                            fields[0].0,
+                            "Expected a column and pairs of row and value",
+                        ));
+                    }
+                    let column = match column_index.get(&fields[0].1) {
+                        Option::Some(&column) => column,
+                        Option::None => {
+                            column_index.insert(fields[0].1.clone(), columns.len());
+                            columns.push(Column {
+                                name: fields[0].1.clone(),
+                                objective_value: 0f64,
+                                entries: vec![],
+                                var_type: if integer {
+                                    VarType::Integer
+                                } else {
+                                    VarType::Continuous
+                                },
+                                lower: Option::None,
+                                upper: 1.0 / 0.0,
+                            });
+                            columns.len() - 1
+                        }
+                    };
+                    for (position, value, row_name) in pairs(&fields)? {
+                        if objective_row.as_ref() == Option::Some(&row_name) {
+                            columns[column].objective_value = value;
+                        } else if let Option::Some(row) = row(position, &row_name)? {
+                            columns[column].entries.push((row, value));
+                        }
+                    }
+                }
+                Option::Some(Section::Rhs) | Option::Some(Section::Ranges) => {
+                    for (position, value, row_name) in pairs(&fields)? {
+                        if objective_row.as_ref() == Option::Some(&row_name) {
+                            return Result::Err(error(
+                                position,
+                                "Objective constants are not supported",
+                            ));
+                        }
+                        if let Option::Some(row) = row(position, &row_name)? {
+                            if section == Option::Some(Section::Rhs) {
+                                rhs[row] = value;
+                            } else {
+                                ranges[row] = Option::Some(value);
+                            }
+                        }
+                    }
+                }
+                Option::Some(Section::Bounds) => {
+                    let kind = fields[0].1.as_str();
+                    // The bound set's name is optional, the bound types without a value need none
+                    let (column_field, value_field) = match (kind, fields.len()) {
+                        ("FR", 4) | ("MI", 4) | ("PL", 4) | ("BV", 4) => (2, Option::None),
+                        ("FR", 3) | ("MI", 3) | ("PL", 3) | ("BV", 3)
+                            if column_index.contains_key(&fields[2].1) =>
+                        {
+                            (2, Option::None)
+                        }
+                        ("FR", _) | ("MI", _) | ("PL", _) | ("BV", _) if fields.len() >= 2 => {
+                            (1, Option::None)
+                        }
+                        (_, 4) => (2, Option::Some(3)),
+                        (_, 3) => (1, Option::Some(2)),
+                        _ => {
+                            return Result::Err(error(
+                                fields[0].0,
+                                "Expected a bound type, a column and a value",
+                            ))
+                        }
+                    };
+                    let (position, column_name) = &fields[column_field];
+                    let column = match column_index.get(column_name) {
+                        Option::Some(&column) => &mut columns[column],
+                        Option::None => return Result::Err(error(*position, "Unknown column")),
+                    };
+                    let value = match value_field {
+                        Option::Some(field) => number(&fields[field], line)?,
+                        Option::None => 0f64,
+                    };
+                    match kind {
+                        "UP" | "UI" => {
+                            column.upper = value;
+                            // A negative upper bound without a lower one frees the lower bound
+                            if value < 0f64 && column.lower.is_none() {
+                                column.lower = Option::Some(-1.0 / 0.0);
+                            }
+                        }
+                        "LO" | "LI" => column.lower = Option::Some(value),
+                        "FX" => {
+                            column.lower = Option::Some(value);
+                            column.upper = value;
+                        }
+                        "FR" => {
+                            column.lower = Option::Some(-1.0 / 0.0);
+                            column.upper = 1.0 / 0.0;
+                        }
+                        "MI" => column.lower = Option::Some(-1.0 / 0.0),
+                        "PL" => column.upper = 1.0 / 0.0,
+                        "BV" => column.var_type = VarType::Binary,
+                        _ => return Result::Err(error(fields[0].0, "Unsupported bound type")),
+                    }
+                    if kind == "UI" || kind == "LI" {
+                        column.var_type = VarType::Integer;
+                    }
+                }
+                Option::Some(Section::Name) | Option::None => {
+                    return Result::Err(error(fields[0].0, "Data outside of a section"))
+                }
+            }
+        }
+        if !ended {
+            return Result::Err(ParseError {
+                line: mps.lines().count() + 1,
+                column: 1,
+                message: "Missing ENDATA",
+            });
+        }
+        let mut model = Model::new(&name, objective);
+        let vars: Vec<Var> = columns
+            .iter()
+            .map(|column| model.reg_var_with_name(column.objective_value, &column.name))
+            .collect();
+        let mut sums: Vec<Vec<Summand>> = rows.iter().map(|_| vec![]).collect();
+        for (column, var) in columns.iter().zip(vars.iter()) {
+            if column.var_type != VarType::Binary {
+                model.set_var_bounds(var, column.lower.unwrap_or(0f64), column.upper);
+            }
+            model.set_var_type(var, column.var_type);
+            for &(row, value) in column.entries.iter() {
+                sums[row].push(Summand(value, var));
+            }
+        }
+        for (((row_name, op), sum), (&b, &range)) in rows
+            .into_iter()
+            .zip(sums)
+            .zip(rhs.iter().zip(ranges.iter()))
+        {
+            // A range `r` bounds `a x` to `[b - |r|, b]` for `<=`, to `[b, b + |r|]` for `>=` rows, and to
+            // `[b, b + r]` or `[b + r, b]` for equalities
+            let (op, range) = match (op, range) {
+                (Operator::E, Option::Some(range)) if range > 0f64 => (Operator::Ge, range),
+                (Operator::E, Option::Some(range)) if range < 0f64 => (Operator::Le, -range),
+                (Operator::E, _) => (Operator::E, 0f64),
+                (op, Option::Some(range)) => (op, range.abs()),
+                (op, Option::None) => (op, 1.0 / 0.0),
+            };
+            model.reg_constr_with_name(sum, op, b, &row_name);
+            model.constraints.last_mut().unwrap().range = range;
+        }
+        Result::Ok(model)
+    }
+
+    /// Writes the [`Model`](struct.model.html) as the text of an MPS file in the given
+    /// [`MpsFormat`](enum.MpsFormat.html), readable by [`from_mps`](#method.from_mps).
+    ///
+    /// Unnamed variables are called `C1`, `C2`, ..., unnamed constraints `R1`, `R2`, ... by their position. The objective
+    /// row is called `OBJ`, the right side, range and bound sets `RHS`, `RNG` and `BND`.
+    /// # Errors
+    /// This method will return an Error if a name can not be written in the format: An empty name, a name longer than
+    /// 8 characters in the fixed format, or a name containing whitespace in the free format.
+    pub fn write_mps(&self, format: MpsFormat) -> Result<String, LinprogError> {
+        let variable_names: Vec<String> = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(index, variable)| {
+                variable
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("C{}", index + 1))
+            })
+            .collect();
+        let constraint_names: Vec<String> = self
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(index, constraint)| {
+                constraint
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("R{}", index + 1))
+            })
+            .collect();
+        let objective_name = (0..)
+            .map(|index| match index {
+                0 => String::from("OBJ"),
+                _ => format!("OBJ{}", index),
+            })
+            .find(|name| !constraint_names.contains(name))
+            .unwrap();
+        for name in variable_names.iter().chain(constraint_names.iter()) {
+            check_name(name, format).map_err(LinprogError::InvalidName)?;
+        }
+        let mut mps = match format {
+            MpsFormat::Fixed => format!("NAME          {}\n", self.name),
+            MpsFormat::Free => format!("NAME {}\n", self.name),
+        };
+        if let Objective::Max = self.objective {
+            mps.push_str("OBJSENSE\n");
+            mps.push_str(&write_line(["", "MAX", "", "", "", ""], format));
+        }
+        mps.push_str("ROWS\n");
+        mps.push_str(&write_line(["N", &objective_name, "", "", "", ""], format));
+        for (constraint, name) in self.constraints.iter().zip(constraint_names.iter()) {
+            let kind = if constraint.factor < 0f64 {
+                "G"
+            } else if constraint.range == 0f64 {
+                "E"
+            } else {
+                "L"
+            };
+            mps.push_str(&write_line([kind, name, "", "", "", ""], format));
+        }
+        mps.push_str("COLUMNS\n");
+        let mut constraint_of_row = vec![0; self.rhs.len()];
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            constraint_of_row[constraint.row] = index;
+        }
+        let transposed = self.matrix.transpose(self.variables.len());
+        let mut integer = false;
+        for (index, (variable, name)) in
+            self.variables.iter().zip(variable_names.iter()).enumerate()
+        {
+            if integer != (variable.var_type != VarType::Continuous) {
+                integer = !integer;
+                let marker = if integer { "'INTORG'" } else { "'INTEND'" };
+                mps.push_str(&write_line(
+                    ["", "MARKER", "'MARKER'", "", marker, ""],
+                    format,
+                ));
+            }
+            let value = write_number(variable.objective_value, format);
+            mps.push_str(&write_line(
+                ["", name, &objective_name, &value, "", ""],
+                format,
+            ));
+            for (row, value) in transposed.row(index) {
+                let constraint = constraint_of_row[row];
+                let value = write_number(self.constraints[constraint].factor * value, format);
+                mps.push_str(&write_line(
+                    ["", name, &constraint_names[constraint], &value, "", ""],
+                    format,
+                ));
+            }
+        }
+        if integer {
+            mps.push_str(&write_line(
+                ["", "MARKER", "'MARKER'", "", "'INTEND'", ""],
+                format,
+            ));
+        }
+        mps.push_str("RHS\n");
+        for (constraint, name) in self.constraints.iter().zip(constraint_names.iter()) {
+            let b = constraint.factor * self.rhs[constraint.row];
+            if b != 0f64 {
+                mps.push_str(&write_line(
+                    ["", "RHS", name, &write_number(b, format), "", ""],
+                    format,
+                ));
+            }
+        }
+        mps.push_str("RANGES\n");
+        for (constraint, name) in self.constraints.iter().zip(constraint_names.iter()) {
+            if constraint.range.is_finite() && constraint.range != 0f64 {
+                let range = write_number(constraint.range, format);
+                mps.push_str(&write_line(["", "RNG", name, &range, "", ""], format));
+            }
+        }
+        mps.push_str("BOUNDS\n");
+        for (variable, name) in self.variables.iter().zip(variable_names.iter()) {
+            let mut bounds: Vec<(&str, Option<f64>)> = vec![];
+            let (lower, upper) = (variable.lower, variable.upper);
+            if variable.var_type == VarType::Binary {
+                bounds.push(("BV", Option::None));
+            } else if lower == upper {
+                bounds.push(("FX", Option::Some(lower)));
+            } else if lower == -1.0 / 0.0 && upper == 1.0 / 0.0 {
+                bounds.push(("FR", Option::None));
+            } else {
+                if lower == -1.0 / 0.0 {
+                    bounds.push(("MI", Option::None));
+                } else if lower != 0f64 || upper < 0f64 {
+                    // A negative upper bound alone would free the lower bound
+                    bounds.push(("LO", Option::Some(lower)));
+                }
+                if upper != 1.0 / 0.0 {
+                    bounds.push(("UP", Option::Some(upper)));
+                } else if variable.var_type == VarType::Integer && bounds.is_empty() {
+                    // Readers differ on the default upper bound of integer variables
+                    bounds.push(("PL", Option::None));
+                }
+            }
+            for (kind, value) in bounds {
+                let value = value.map_or(String::new(), |value| write_number(value, format));
+                mps.push_str(&write_line([kind, "BND", name, &value, "", ""], format));
+            }
+        }
+        mps.push_str("ENDATA\n");
+        Result::Ok(mps)
+    }
+}
@@ -0,0 +1,52 @@
+//! Serialization of floats which may be infinite, e.g. bounds, in human-readable formats without infinity like JSON.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A float in a human-readable format: A number if finite, otherwise `"inf"`, `"-inf"` or `"NaN"`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Float {
+    Number(f64),
+    Text(String),
+}
+
+/// A float serialized by this module, for optional values.
+#[derive(Serialize, Deserialize)]
+struct Wrapped(#[serde(with = "self")] f64);
+
+pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() && !value.is_finite() {
+        serializer.serialize_str(&value.to_string())
+    } else {
+        serializer.serialize_f64(*value)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    if !deserializer.is_human_readable() {
+        return f64::deserialize(deserializer);
+    }
+    match Float::deserialize(deserializer)? {
+        Float::Number(value) => Result::Ok(value),
+        Float::Text(text) => text
+            .parse()
+            .map_err(|_| D::Error::custom(format!("invalid float: {}", text))),
+    }
+}
+
+/// Serialization of optional floats which may be infinite.
+pub mod option {
+    use super::Wrapped;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.map(Wrapped).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<f64>, D::Error> {
+        Option::<Wrapped>::deserialize(deserializer).map(|value| value.map(|Wrapped(value)| value))
+    }
+}
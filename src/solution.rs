@@ -0,0 +1,40 @@
+//! A snapshot of an optimization's result.
+
+use crate::{LinprogError, Model, SolveStatus};
+
+/// The result of optimizing a [`Model`](struct.model.html), detached from it, e.g. to be stored with the `serde`
+/// feature. The values are in the order of the model's [`vars`](struct.Model.html#method.vars) and
+/// [`constrs`](struct.Model.html#method.constrs).
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution {
+    /// The outcome of the optimization
+    pub status: SolveStatus,
+    /// The objective value, see [`optimum`](struct.Model.html#method.optimum)
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_float::option"))]
+    pub optimum: Option<f64>,
+    /// The values of the variables, if a solution was found
+    pub x: Option<Vec<f64>>,
+    /// The dual values of the constraints, if they were computed
+    pub duals: Option<Vec<f64>>,
+}
+
+impl Model {
+    /// Returns a snapshot of the [`Model`](struct.model.html)'s [`Solution`](struct.Solution.html).
+    /// # Errors
+    /// This method will return an Error if the [`Model`](struct.model.html) has not been optimized (see
+    /// [`optimize`](#method.optimize)).
+    pub fn solution(&self) -> Result<Solution, LinprogError> {
+        let status = self.status.ok_or(LinprogError::NotOptimized)?;
+        Result::Ok(Solution {
+            status,
+            optimum: self.optimum,
+            x: self.variables.iter().map(|variable| variable.x).collect(),
+            duals: self
+                .constraints
+                .iter()
+                .map(|constraint| constraint.dual)
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,197 @@
+//! Sparse LU factorization of a simplex basis with product-form updates.
+
+/// A sparse vector or matrix column: `(index, value)` pairs.
+pub type SparseColumn = Vec<(usize, f64)>;
+
+/// Number of product-form updates after which the basis is factorized again.
+pub const REFACTORIZATION_INTERVAL: usize = 64;
+
+/// Ratio to the largest candidate a pivot element must reach, leaving room to choose sparse rows.
+const PIVOT_THRESHOLD: f64 = 0.1;
+
+/// One elimination step of the factorization.
+struct Step {
+    /// Row of the basis matrix the step pivots on
+    row: usize,
+    /// Basis position (column of the basis matrix) the step eliminates
+    position: usize,
+    /// Multipliers of the rows eliminated below the pivot
+    lower: SparseColumn,
+    /// Entries of `U` above the diagonal, indexed by earlier steps
+    upper: SparseColumn,
+    /// The pivot element
+    diagonal: f64,
+}
+
+/// An elementary column transformation replacing the basis column at `position` by `column`.
+struct Eta {
+    position: usize,
+    column: SparseColumn,
+    pivot: f64,
+}
+
+/// The factorization `B = L U` (up to permutations) of a basis matrix, followed by an eta file.
+pub struct Lu {
+    size: usize,
+    steps: Vec<Step>,
+    etas: Vec<Eta>,
+}
+
+impl Lu {
+    /// Factorizes the basis matrix whose columns are given, `None` if it is singular up to `pivot_tolerance`.
+    ///
+    /// The columns are eliminated left-looking (sparsest first), choosing among the pivot candidates reaching the
+    /// threshold the one in the sparsest row.
+    pub fn factorize(columns: &[&SparseColumn], pivot_tolerance: f64) -> Option<Lu> {
+        let size = columns.len();
+        let mut row_counts = vec![0usize; size];
+        for column in columns {
+            for &(row, _) in column.iter() {
+                row_counts[row] += 1;
+            }
+        }
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by_key(|&position| columns[position].len());
+        let mut step_of_row: Vec<Option<usize>> = vec![Option::None; size];
+        let mut steps: Vec<Step> = Vec::with_capacity(size);
+        let mut work = vec![0f64; size];
+        for position in order {
+            for &(row, value) in columns[position].iter() {
+                work[row] = value;
+            }
+            apply_lower(&steps, &mut work);
+            let mut upper: SparseColumn = vec![];
+            let mut largest = 0f64;
+            for (row, &value) in work.iter().enumerate() {
+                if value == 0f64 {
+                    continue;
+                }
+                match step_of_row[row] {
+                    Option::Some(step) => upper.push((step, value)),
+                    Option::None => largest = largest.max(value.abs()),
+                }
+            }
+            if largest <= pivot_tolerance {
+                return Option::None;
+            }
+            let pivot_row = (0..size)
+                .filter(|&row| {
+                    step_of_row[row].is_none() && work[row].abs() >= PIVOT_THRESHOLD * largest
+                })
+                .min_by_key(|&row| row_counts[row])
+                .unwrap();
+            let diagonal = work[pivot_row];
+            let lower: SparseColumn = work
+                .iter()
+                .enumerate()
+                .filter(|&(row, &value)| {
+                    value != 0f64 && row != pivot_row && step_of_row[row].is_none()
+                })
+                .map(|(row, &value)| (row, value / diagonal))
+                .collect();
+            step_of_row[pivot_row] = Option::Some(steps.len());
+            steps.push(Step {
+                row: pivot_row,
+                position,
+                lower,
+                upper,
+                diagonal,
+            });
+            work.iter_mut().for_each(|value| *value = 0f64);
+        }
+        Option::Some(Lu {
+            size,
+            steps,
+            etas: vec![],
+        })
+    }
+
+    /// Returns the number of product-form updates since the last factorization.
+    pub fn update_count(&self) -> usize {
+        self.etas.len()
+    }
+
+    /// Replaces the basis column at `position`, `column` being the solution of `B x = a` for the new column `a`.
+    pub fn update(&mut self, position: usize, column: &[f64]) {
+        self.etas.push(Eta {
+            position,
+            column: column
+                .iter()
+                .enumerate()
+                .filter(|&(index, &value)| value != 0f64 && index != position)
+                .map(|(index, &value)| (index, value))
+                .collect(),
+            pivot: column[position],
+        });
+    }
+
+    /// Solves `B x = a` (FTRAN), `a` indexed by rows, `x` by basis positions.
+    pub fn solve(&self, a: &[f64]) -> Vec<f64> {
+        let mut work = a.to_vec();
+        apply_lower(&self.steps, &mut work);
+        let mut y: Vec<f64> = self.steps.iter().map(|step| work[step.row]).collect();
+        let mut x = vec![0f64; self.size];
+        for (index, step) in self.steps.iter().enumerate().rev() {
+            let z = y[index] / step.diagonal;
+            if z != 0f64 {
+                for &(earlier, value) in step.upper.iter() {
+                    y[earlier] -= value * z;
+                }
+            }
+            x[step.position] = z;
+        }
+        for eta in self.etas.iter() {
+            let pivot_value = x[eta.position] / eta.pivot;
+            if pivot_value != 0f64 {
+                for &(index, value) in eta.column.iter() {
+                    x[index] -= value * pivot_value;
+                }
+            }
+            x[eta.position] = pivot_value;
+        }
+        x
+    }
+
+    /// Solves `B^T y = c` (BTRAN), `c` indexed by basis positions, `y` by rows.
+    pub fn solve_transposed(&self, c: &[f64]) -> Vec<f64> {
+        let mut c = c.to_vec();
+        for eta in self.etas.iter().rev() {
+            let sum: f64 = eta
+                .column
+                .iter()
+                .map(|&(index, value)| value * c[index])
+                .sum();
+            c[eta.position] = (c[eta.position] - sum) / eta.pivot;
+        }
+        let mut z: Vec<f64> = Vec::with_capacity(self.size);
+        for step in self.steps.iter() {
+            let sum: f64 = step
+                .upper
+                .iter()
+                .map(|&(earlier, value)| value * z[earlier])
+                .sum();
+            z.push((c[step.position] - sum) / step.diagonal);
+        }
+        let mut y = vec![0f64; self.size];
+        for (step, &value) in self.steps.iter().zip(z.iter()) {
+            y[step.row] = value;
+        }
+        for step in self.steps.iter().rev() {
+            let sum: f64 = step.lower.iter().map(|&(row, value)| value * y[row]).sum();
+            y[step.row] -= sum;
+        }
+        y
+    }
+}
+
+/// Applies the elimination steps (`L^-1`) to a dense vector indexed by rows.
+fn apply_lower(steps: &[Step], work: &mut [f64]) {
+    for step in steps {
+        let value = work[step.row];
+        if value != 0f64 {
+            for &(row, multiplier) in step.lower.iter() {
+                work[row] -= multiplier * value;
+            }
+        }
+    }
+}
@@ -1,226 +1,194 @@
 #[cfg(test)]
 mod tests;
 
-use std::collections::HashMap;
+mod lu;
+mod pricing;
+mod problem;
+mod ranging;
+mod revised;
+mod simplex;
+mod tableau;
 
-fn is_optimal(tableau: &Vec<Vec<f64>>) -> bool {
-    return !tableau[0][..tableau[0].len() - 1].iter().any(|&x| x > 0f64);
-}
+pub use pricing::Pricing;
+pub use problem::Problem;
+pub use ranging::Ranging;
 
-fn is_unbounded(tableau: &Vec<Vec<f64>>, pivot_column: usize) -> bool {
-    return !tableau[1..].iter().any(|x| x[pivot_column] > 0f64);
+use revised::Revised;
+use std::collections::HashMap;
+use std::fmt;
+use tableau::Tableau;
+
+/// The outcome of an optimization.
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolveStatus {
+    /// An optimal solution was found
+    Optimal,
+    /// No solution satisfies all constraints
+    Infeasible,
+    /// The objective can be improved without limit
+    Unbounded,
+    /// The simplex algorithm stopped at the iteration limit
+    IterationLimit,
+    /// Branch-and-bound stopped at the node limit
+    NodeLimit,
+    /// The simplex algorithm produced non-finite values
+    NumericalFailure,
 }
 
-fn optimum(tableau: &Vec<Vec<f64>>, variable_count: usize) -> (HashMap<usize, f64>, f64) {
-    let mut solution: HashMap<usize, f64> = HashMap::with_capacity(variable_count);
-    for column_index in 0..variable_count {
-        for row in tableau[1..].iter() {
-            if row[column_index] == 1f64 {
-                if solution.contains_key(&column_index) {
-                    solution.insert(column_index, 0f64);
-                    break;
-                } else {
-                    solution.insert(column_index, *row.last().unwrap());
-                }
-            } else if row[column_index] != 0f64 {
-                solution.insert(column_index, 0f64);
-                break;
-            }
+impl fmt::Display for SolveStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveStatus::Optimal => write!(f, "optimal"),
+            SolveStatus::Infeasible => write!(f, "infeasible"),
+            SolveStatus::Unbounded => write!(f, "unbounded"),
+            SolveStatus::IterationLimit => write!(f, "iteration limit"),
+            SolveStatus::NodeLimit => write!(f, "node limit"),
+            SolveStatus::NumericalFailure => write!(f, "numerical failure"),
         }
     }
-    (solution, -*tableau[0].last().unwrap())
 }
 
-fn pivot(tableau: &Vec<Vec<f64>>) -> Option<(usize, usize)> {
-    let mut max_column: (usize, f64) = (0, -1.0 / 0.0);
-    for (column_index, &value) in tableau[0][..tableau[0].len() - 1].iter().enumerate() {
-        if value > max_column.1 {
-            max_column = (column_index, value);
-        }
-    }
-    if is_unbounded(tableau, max_column.0) {
-        return Option::None;
-    }
-    let right_side_column = tableau[0].len() - 1;
-    let mut min_row: (usize, f64) = (0, 1.0 / 0.0);
-    for (row_index, row) in tableau[1..].iter().enumerate() {
-        if row[max_column.0] > 0f64 && (row[right_side_column] / row[max_column.0]) < min_row.1 {
-            min_row = (row_index, row[right_side_column] / row[max_column.0]);
-        } else if row[max_column.0] > 0f64
-            && (row[right_side_column] / row[max_column.0]) == min_row.1
-        {
-            panic!("Possible degeneracy");
-        }
-    }
-    Option::Some((min_row.0 + 1, max_column.0))
+/// Rule resolving ties in the simplex algorithm's ratio test, preventing cycling on degenerate models.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AntiCycling {
+    /// Bland's rule: The entering and the leaving variable are the candidates with the smallest index.
+    Bland,
+    /// Lexicographic ratio test: Ties are resolved by comparing the rows (scaled by the pivot column) lexicographically.
+    #[default]
+    Lexicographic,
 }
 
-fn next(tableau: &mut Vec<Vec<f64>>, (pivot_row, pivot_column): (usize, usize)) {
-    let pivot = tableau[pivot_row][pivot_column];
-    tableau[pivot_row] = tableau[pivot_row].iter().map(|&x| x / pivot).collect();
-    for row_index in 0..tableau.len() {
-        if row_index != pivot_row {
-            tableau[row_index] = tableau[row_index]
-                .iter()
-                .enumerate()
-                .map(|(column, &x)| {
-                    x - tableau[row_index][pivot_column] * tableau[pivot_row][column]
-                })
-                .collect();
-        }
-    }
+/// Absolute values below which the simplex algorithm ignores rounding errors.
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerances {
+    /// Violation of a bound (a negative right side, a positive phase one optimum) up to which a solution is feasible.
+    /// The default is `1e-9`.
+    pub primal_feasibility: f64,
+    /// Reduced cost up to which a variable is not entering the basis, i.e. up to which a tableau is optimal.
+    /// The default is `1e-9`.
+    pub dual_feasibility: f64,
+    /// Absolute value up to which a tableau entry is not chosen as pivot element.
+    /// The default is `1e-10`.
+    pub pivot: f64,
 }
 
-pub fn optimize(
-    tableau: &mut Vec<Vec<f64>>,
-    variable_count: Option<usize>,
-) -> (Option<HashMap<usize, f64>>, f64) {
-    let position_b = tableau[0].len() - 1;
-    for row in tableau[1..].iter() {
-        if row[position_b] < 0f64 {
-            return optimize_two_phases(tableau, position_b);
-        }
-    }
-    let mut pivot_element: (usize, usize);
-    while !is_optimal(tableau) {
-        match pivot(tableau) {
-            Option::Some(x) => pivot_element = x,
-            Option::None => return (Option::None, 1.0 / 0.0),
+impl Default for Tolerances {
+    fn default() -> Self {
+        Tolerances {
+            primal_feasibility: 1e-9,
+            dual_feasibility: 1e-9,
+            pivot: 1e-10,
         }
-        next(tableau, pivot_element);
     }
-    let variable_count = match variable_count {
-        Option::Some(variable_count) => variable_count,
-        Option::None => (position_b) - (tableau.len() - 1),
-    };
-    let (map, value) = optimum(tableau, variable_count);
-    (Option::Some(map), value)
 }
 
-fn optimize_two_phases(
-    tableau: &mut Vec<Vec<f64>>,
-    position_b: usize,
-) -> (Option<HashMap<usize, f64>>, f64) {
-    // Count #AV needed
-    let mut number_artificial_variables = 0;
-    for row in tableau[1..].iter() {
-        if row[position_b] < 0f64 {
-            number_artificial_variables += 1;
-        }
-    }
-    // Phase one
-    let phase_two_objective_function =
-        prepare_phase_one(tableau, number_artificial_variables, position_b);
-    let (_, value) = optimize(tableau, Option::Some(position_b));
-    // Check if model is feasable
-    if value != 0f64 {
-        panic!("Model is infeasable");
-    }
-    // Phase two
-    prepare_phase_two(
-        tableau,
-        phase_two_objective_function,
-        number_artificial_variables,
-    );
-    let (solution, value) = optimize(tableau, Option::None);
-    (solution, value)
+/// Implementation of the simplex algorithm.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Backend {
+    /// The dense tableau, updated completely on every pivot.
+    #[default]
+    Tableau,
+    /// The revised simplex algorithm: Only an LU factorization of the basis is kept, updated in product form and
+    /// factorized again periodically. Suited for large and sparse models.
+    Revised,
 }
 
-fn prepare_phase_one(
-    tableau: &mut Vec<Vec<f64>>,
-    number_artificial_variables: usize,
-    position_b: usize,
-) -> Vec<f64> {
-    let mut phase_one_objective_function: Vec<f64> = vec![0f64; position_b + 1];
-    // Add AV to constraints
-    for (row_index, row) in tableau[1..].iter_mut().enumerate() {
-        if row[position_b] < 0f64 {
-            // Change +/- and build phase one objective function
-            for (variable, value) in row.iter_mut().enumerate() {
-                *value *= -1f64;
-                phase_one_objective_function[variable] += *value;
-            }
-            let b = row.pop().unwrap();
-            for i in 0..number_artificial_variables {
-                if i == row_index {
-                    row.push(1f64);
-                } else {
-                    row.push(0f64);
-                }
-            }
-            row.push(b);
-        } else {
-            let b = row.pop().unwrap();
-            for _ in 0..number_artificial_variables {
-                row.push(0f64);
-            }
-            row.push(b);
-        }
-    }
-    // Add zeros for AV in phase one objective function
-    let z = phase_one_objective_function.pop().unwrap();
-    for _ in 0..number_artificial_variables {
-        phase_one_objective_function.push(0f64);
-    }
-    phase_one_objective_function.push(z);
-    let phase_two_objective_function: Vec<f64> = tableau[0].to_vec();
-    tableau[0] = phase_one_objective_function;
-    phase_two_objective_function
+/// Handling of the artificial variables in rows the slack basis is infeasible for (negative right sides, equalities).
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Initialization {
+    /// Phase one minimizes the sum of the artificial variables, phase two optimizes the objective.
+    #[default]
+    TwoPhases,
+    /// A single phase optimizing the objective with the artificial variables penalized by the given `M`.
+    /// `M` should exceed the model's coefficients by far: If artificial variables are left in the solution, phase one
+    /// continues from it to decide whether the problem is infeasible.
+    BigM(f64),
 }
 
-fn prepare_phase_two(
-    tableau: &mut Vec<Vec<f64>>,
-    mut phase_two_objective_function: Vec<f64>,
-    number_artificial_variables: usize,
-) {
-    // Calculate phase two objective function
-    let last_index = phase_two_objective_function.len() - 1;
-    for variable in 0..phase_two_objective_function.len() {
-        if phase_two_objective_function[variable] != 0f64 && is_base_variable(tableau, variable) {
-            // Variable should be displayed by non base variables
-            for row in tableau[1..].iter() {
-                if row[variable] == 1f64 {
-                    for column_index in 0..phase_two_objective_function.len() - 1 {
-                        if column_index != variable {
-                            phase_two_objective_function[column_index] +=
-                                phase_two_objective_function[variable] * (-row[column_index]);
-                        }
-                    }
-                    phase_two_objective_function[last_index] +=
-                        phase_two_objective_function[variable] * row[row.len() - 1];
-                }
-            }
-            phase_two_objective_function[variable] = 0f64;
-        }
-    }
-    phase_two_objective_function[last_index] *= -1f64;
-    tableau[0] = phase_two_objective_function;
-    // Remove AVs
-    for row in tableau[1..].iter_mut() {
-        let b = row.pop().unwrap();
-        for _ in 0..number_artificial_variables {
-            row.pop();
-        }
-        row.push(b);
-    }
+/// Warning on `M` being smaller than [`BIG_M_RATIO`](constant.BIG_M_RATIO.html) times the largest objective
+/// coefficient or constraint entry.
+pub const BIG_M_WARNING: &str = "Big-M is small relative to the coefficients";
+
+/// Ratio of `M` to the model's largest coefficient below which [`BIG_M_WARNING`](constant.BIG_M_WARNING.html) is given.
+pub const BIG_M_RATIO: f64 = 1e3;
+
+/// Options for the simplex algorithm.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverOptions {
+    /// Maximal number of pivots per linear program (per node in branch-and-bound), `None` for no limit.
+    pub iteration_limit: Option<usize>,
+    /// Rule resolving ties in the ratio test. The default is [`Lexicographic`](enum.AntiCycling.html#variant.Lexicographic).
+    pub anti_cycling: AntiCycling,
+    /// Rule choosing the entering variable. The default is [`Dantzig`](enum.Pricing.html#variant.Dantzig).
+    /// Ignored if [`Bland`](enum.AntiCycling.html#variant.Bland)'s rule is used.
+    pub pricing: Pricing,
+    /// Tolerances for the feasibility, optimality and pivot checks.
+    pub tolerances: Tolerances,
+    /// Implementation of the simplex algorithm. The default is [`Tableau`](enum.Backend.html#variant.Tableau).
+    pub backend: Backend,
+    /// Whether to range the objective coefficients and right sides of an optimal solution (sensitivity analysis).
+    /// The default is `false`.
+    pub sensitivity: bool,
+    /// Start of the simplex algorithm if the slack basis is infeasible.
+    /// The default is [`TwoPhases`](enum.Initialization.html#variant.TwoPhases).
+    pub initialization: Initialization,
 }
 
-fn is_base_variable(tableau: &Vec<Vec<f64>>, variable: usize) -> bool {
-    let mut found_one = false;
-    let mut ret = false;
-    for row in tableau[1..].iter() {
-        if row[variable] == 1f64 {
-            if found_one == true {
-                ret = false;
-                break;
-            } else {
-                found_one = true;
-                ret = true;
-            }
-        } else if row[variable] != 0f64 {
-            ret = false;
-            break;
-        }
+/// A variable's status in a basis, used to start the simplex algorithm from a previous optimal basis.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BasisStatus {
+    /// The variable is in the basis
+    Basic,
+    /// The variable is not in the basis and at its lower bound (zero if free)
+    AtLower,
+    /// The variable is not in the basis and at its upper bound
+    AtUpper,
+}
+
+/// The result of optimizing a tableau.
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    pub status: SolveStatus,
+    /// The variables' values, if optimal.
+    pub x: Option<HashMap<usize, f64>>,
+    /// The (maximized) objective value, `inf` if unbounded, `-inf` if infeasible and `NaN` if the algorithm stopped early.
+    pub value: f64,
+    /// The dual values (shadow prices) of the rows, if optimal.
+    pub duals: Option<Vec<f64>>,
+    /// The reduced costs of the variables (excluding slacks), if optimal.
+    pub reduced_costs: Option<Vec<f64>>,
+    /// The sensitivity analysis, if optimal and enabled.
+    pub ranging: Option<Ranging>,
+    /// The status of each variable, followed by each row's slack variable, if optimal.
+    pub basis: Option<Vec<BasisStatus>>,
+    /// The number of pivots.
+    pub iterations: usize,
+    /// Doubts about the result, e.g. [`BIG_M_WARNING`](constant.BIG_M_WARNING.html).
+    pub warnings: Vec<&'static str>,
+    /// A Farkas certificate, if infeasible: Multipliers `y` of the rows, non-negative for inequalities, such that
+    /// `y b` is less than the minimum of `y A x` within the variables' bounds.
+    pub farkas: Option<Vec<f64>>,
+    /// A direction `r` of the variables, if unbounded: `A r <= 0` (zero on equalities) and `c r > 0`, only
+    /// variables with an infinite bound moving, towards it. The objective improves without limit along it.
+    pub ray: Option<Vec<f64>>,
+}
+
+/// Optimizes a [`Problem`](struct.Problem.html) using the backend chosen in the options.
+pub fn solve(problem: &Problem, options: &SolverOptions) -> Solution {
+    match options.backend {
+        Backend::Tableau => simplex::solve::<Tableau>(problem, options),
+        Backend::Revised => simplex::solve::<Revised>(problem, options),
     }
-    ret
+}
+
+/// Optimizes a tableau in the format of [`Problem::tableau`](struct.Problem.html#method.tableau).
+#[cfg(test)]
+pub fn optimize(tableau: &[Vec<f64>], options: &SolverOptions) -> Solution {
+    solve(&Problem::from_tableau(tableau), options)
 }
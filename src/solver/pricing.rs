@@ -0,0 +1,136 @@
+use super::{AntiCycling, Backend, SolverOptions};
+
+/// Rule choosing the entering variable among the columns with positive reduced cost.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pricing {
+    /// Dantzig's rule: The column with the largest reduced cost.
+    #[default]
+    Dantzig,
+    /// Steepest edge: The column with the largest reduced cost relative to the column's norm.
+    /// The [`Revised`](enum.Backend.html#variant.Revised) backend approximates it by Devex.
+    SteepestEdge,
+    /// Devex: The column with the largest reduced cost relative to approximated column norms (reference weights).
+    Devex,
+    /// Partial pricing: Dantzig's rule, restricted to the first segment of the given number of columns containing a candidate.
+    /// The segments are scanned round robin, starting after the last chosen segment.
+    Partial(usize),
+}
+
+/// A pricing rule's state during one phase of the simplex algorithm.
+pub struct Pricer {
+    pricing: Pricing,
+    anti_cycling: AntiCycling,
+    /// Reduced cost a candidate must exceed
+    dual_feasibility: f64,
+    /// Devex reference weights per column
+    weights: Vec<f64>,
+    /// First column of the segment scanned next by partial pricing
+    offset: usize,
+}
+
+impl Pricer {
+    pub fn new(options: &SolverOptions, column_count: usize) -> Self {
+        let pricing = match (options.pricing, options.backend) {
+            // Column norms are too expensive for the revised simplex algorithm
+            (Pricing::SteepestEdge, Backend::Revised) => Pricing::Devex,
+            (pricing, _) => pricing,
+        };
+        Pricer {
+            pricing,
+            anti_cycling: options.anti_cycling,
+            dual_feasibility: options.tolerances.dual_feasibility,
+            weights: vec![1f64; column_count],
+            offset: 0,
+        }
+    }
+
+    /// Returns the entering column given the improvements per unit (the reduced costs in the direction a variable can
+    /// move), at least one exceeding the dual feasibility tolerance.
+    ///
+    /// `column_norm` returns the squared norm of a column (plus one) for steepest edge pricing.
+    pub fn choose<F>(&mut self, reduced_costs: &[f64], column_norm: F) -> usize
+    where
+        F: Fn(usize) -> f64,
+    {
+        let tolerance = self.dual_feasibility;
+        if self.anti_cycling == AntiCycling::Bland {
+            // Bland's rule overrides the pricing rule
+            return reduced_costs.iter().position(|&d| d > tolerance).unwrap();
+        }
+        match self.pricing {
+            Pricing::Dantzig => {
+                best_column(reduced_costs, tolerance, 0..reduced_costs.len(), |j| {
+                    reduced_costs[j]
+                })
+                .unwrap()
+            }
+            Pricing::SteepestEdge => {
+                best_column(reduced_costs, tolerance, 0..reduced_costs.len(), |j| {
+                    reduced_costs[j] * reduced_costs[j] / column_norm(j)
+                })
+                .unwrap()
+            }
+            Pricing::Devex => best_column(reduced_costs, tolerance, 0..reduced_costs.len(), |j| {
+                reduced_costs[j] * reduced_costs[j] / self.weights[j]
+            })
+            .unwrap(),
+            Pricing::Partial(segment) => {
+                let column_count = reduced_costs.len();
+                let segment = segment.clamp(1, column_count);
+                let mut start = self.offset % column_count;
+                loop {
+                    let end = (start + segment).min(column_count);
+                    let candidate =
+                        best_column(reduced_costs, tolerance, start..end, |j| reduced_costs[j]);
+                    start = end % column_count;
+                    if let Option::Some(column) = candidate {
+                        self.offset = start;
+                        return column;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether [`update`](#method.update) uses the pivot row.
+    pub fn needs_pivot_row(&self) -> bool {
+        self.pricing == Pricing::Devex
+    }
+
+    /// Updates the state before pivoting on the given row and column, `leaving` being the leaving column.
+    pub fn update(&mut self, row: &[f64], pivot_column: usize, leaving: usize) {
+        if !self.needs_pivot_row() {
+            return;
+        }
+        let pivot = row[pivot_column];
+        let pivot_weight = self.weights[pivot_column];
+        for (column, weight) in self.weights.iter_mut().enumerate() {
+            if column != pivot_column && row[column] != 0f64 {
+                let ratio = row[column] / pivot;
+                *weight = weight.max(ratio * ratio * pivot_weight);
+            }
+        }
+        self.weights[leaving] = (pivot_weight / (pivot * pivot)).max(1f64);
+    }
+}
+
+/// Returns the column with a reduced cost above `tolerance` and the highest score, `None` if there is no candidate.
+fn best_column<I, F>(reduced_costs: &[f64], tolerance: f64, columns: I, score: F) -> Option<usize>
+where
+    I: Iterator<Item = usize>,
+    F: Fn(usize) -> f64,
+{
+    let mut best: Option<(usize, f64)> = Option::None;
+    for column in columns.filter(|&column| reduced_costs[column] > tolerance) {
+        let value = score(column);
+        let better = match best {
+            Option::Some((_, best_value)) => value > best_value,
+            Option::None => true,
+        };
+        if better {
+            best = Option::Some((column, value));
+        }
+    }
+    best.map(|(column, _)| column)
+}
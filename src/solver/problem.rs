@@ -0,0 +1,76 @@
+use super::BasisStatus;
+use crate::sparse::SparseMatrix;
+
+/// A linear program `max c x, b - r <= A x <= b, l <= x <= u` as passed to the solver.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Problem {
+    /// The objective coefficients `c` (maximized)
+    pub objective: Vec<f64>,
+    /// The constraint matrix `A`, one row per constraint
+    pub constraints: SparseMatrix,
+    /// The right sides `b`
+    pub rhs: Vec<f64>,
+    /// The ranges `r` of the rows, the upper bounds of their slack variables: `inf` for inequalities `a x <= b`, zero
+    /// for equalities `a x = b`
+    pub range: Vec<f64>,
+    /// The variables' lower bounds `l`, `-inf` if unbounded
+    pub lower: Vec<f64>,
+    /// The variables' upper bounds `u`, `inf` if unbounded
+    pub upper: Vec<f64>,
+    /// Directions in which the right sides are ranged if [`sensitivity`](struct.SolverOptions.html#structfield.sensitivity)
+    /// is enabled. Each is given as `(row, factor)` pairs, the right side of `row` changing by `factor` per unit.
+    pub ranged_rhs: Vec<Vec<(usize, f64)>>,
+    /// A basis to start from, given like [`Solution::basis`](struct.Solution.html#structfield.basis).
+    /// It is used if it is primal feasible, or dual feasible for the dual simplex algorithm.
+    pub basis: Option<Vec<BasisStatus>>,
+}
+
+impl Problem {
+    /// Returns the number of variables (excluding slacks).
+    pub fn variable_count(&self) -> usize {
+        self.objective.len()
+    }
+
+    /// Builds the dense tableau: The objective row `[c | 0 | 0]` followed by the rows `[A | I | b]`.
+    #[cfg(test)]
+    pub fn tableau(&self) -> Vec<Vec<f64>> {
+        let variable_count = self.variable_count();
+        let row_count = self.rhs.len();
+        let mut tableau: Vec<Vec<f64>> = Vec::with_capacity(row_count + 1);
+        let mut objective = self.objective.clone();
+        objective.extend(vec![0f64; row_count + 1]);
+        tableau.push(objective);
+        for (row_index, &b) in self.rhs.iter().enumerate() {
+            let mut row = vec![0f64; variable_count + row_count + 1];
+            for (column, value) in self.constraints.row(row_index) {
+                row[column] = value;
+            }
+            row[variable_count + row_index] = 1f64;
+            row[variable_count + row_count] = b;
+            tableau.push(row);
+        }
+        tableau
+    }
+
+    /// Reads a tableau in the format of [`tableau`](#method.tableau), the slack columns being the last before `b`.
+    /// The variables are non-negative.
+    #[cfg(test)]
+    pub fn from_tableau(tableau: &[Vec<f64>]) -> Problem {
+        let position_b = tableau[0].len() - 1;
+        let variable_count = position_b - (tableau.len() - 1);
+        let mut constraints = SparseMatrix::new();
+        for row in tableau[1..].iter() {
+            constraints.push_row(row[..variable_count].iter().cloned().enumerate().collect());
+        }
+        Problem {
+            objective: tableau[0][..variable_count].to_vec(),
+            constraints,
+            rhs: tableau[1..].iter().map(|row| row[position_b]).collect(),
+            range: vec![1.0 / 0.0; tableau.len() - 1],
+            lower: vec![0f64; variable_count],
+            upper: vec![1.0 / 0.0; variable_count],
+            ranged_rhs: vec![],
+            basis: Option::None,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Sensitivity analysis of an optimal basis.
+
+use super::Tolerances;
+
+/// The ranges within which the objective coefficients or right sides can move without changing the optimal basis.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Ranging {
+    /// Lower and upper bound of each variable's (maximized) objective coefficient keeping the basis optimal.
+    pub cost: Vec<(f64, f64)>,
+    /// Lower and upper bound of the change along each of the problem's
+    /// [`ranged_rhs`](struct.Problem.html#structfield.ranged_rhs) keeping the basis feasible.
+    pub rhs: Vec<(f64, f64)>,
+}
+
+/// The directions a non-base variable can move in from its value.
+#[derive(Clone, Copy, Debug)]
+pub struct Movable {
+    /// The variable is below its upper bound
+    pub increase: bool,
+    /// The variable is above its lower bound
+    pub decrease: bool,
+}
+
+/// Returns the interval of a variable's objective coefficient `c` keeping the basis optimal.
+///
+/// For a base variable, `row` holds the reduced cost, the entry in the variable's row and the directions of every
+/// non-base variable.
+pub fn cost_interval<I>(
+    c: f64,
+    reduced_cost: f64,
+    movable: Movable,
+    row: Option<I>,
+    tolerances: &Tolerances,
+) -> (f64, f64)
+where
+    I: Iterator<Item = (f64, f64, Movable)>,
+{
+    match row {
+        // The reduced cost must not become an improving direction
+        Option::None => (
+            if movable.decrease {
+                c - reduced_cost
+            } else {
+                -1.0 / 0.0
+            },
+            if movable.increase {
+                c - reduced_cost
+            } else {
+                1.0 / 0.0
+            },
+        ),
+        // The reduced costs `d - delta * alpha` of the non-base variables must stay non-positive where they can
+        // increase and non-negative where they can decrease
+        Option::Some(row) => {
+            let mut interval = (-1.0 / 0.0, 1.0 / 0.0);
+            for (d, alpha, movable) in row {
+                if alpha.abs() <= tolerances.pivot {
+                    continue;
+                }
+                let positive = alpha > 0f64;
+                if (movable.increase && positive) || (movable.decrease && !positive) {
+                    interval.0 = f64::max(interval.0, d / alpha);
+                }
+                if (movable.increase && !positive) || (movable.decrease && positive) {
+                    interval.1 = f64::min(interval.1, d / alpha);
+                }
+            }
+            (c + interval.0, c + interval.1)
+        }
+    }
+}
+
+/// Returns the interval of changes `delta` keeping the base variables' values `x_b + delta * beta` within their
+/// bounds, `bounds` holding the value, lower and upper bound of each base variable.
+pub fn rhs_interval(
+    bounds: &[(f64, f64, f64)],
+    beta: &[f64],
+    tolerances: &Tolerances,
+) -> (f64, f64) {
+    let mut interval = (-1.0 / 0.0, 1.0 / 0.0);
+    for (&(x, lower, upper), &beta) in bounds.iter().zip(beta.iter()) {
+        let (to_lower, to_upper) = ((lower - x).min(0f64), (upper - x).max(0f64));
+        if beta > tolerances.pivot {
+            interval.0 = f64::max(interval.0, to_lower / beta);
+            interval.1 = f64::min(interval.1, to_upper / beta);
+        } else if beta < -tolerances.pivot {
+            interval.0 = f64::max(interval.0, to_upper / beta);
+            interval.1 = f64::min(interval.1, to_lower / beta);
+        }
+    }
+    interval
+}
@@ -0,0 +1,59 @@
+//! The revised simplex algorithm on an LU-factorized basis.
+//!
+//! Only the basis is factorized, the columns of `[A | I]` are kept sparse.
+
+use super::lu::{Lu, SparseColumn, REFACTORIZATION_INTERVAL};
+use super::simplex::Kernel;
+
+/// The LU factorization of the basis matrix.
+pub struct Revised {
+    row_count: usize,
+    lu: Lu,
+}
+
+impl Kernel for Revised {
+    fn factorize(
+        columns: &[SparseColumn],
+        basis: &[usize],
+        row_count: usize,
+        pivot_tolerance: f64,
+    ) -> Option<Self> {
+        let columns: Vec<&SparseColumn> =
+            basis.iter().map(|&variable| &columns[variable]).collect();
+        Lu::factorize(&columns, pivot_tolerance).map(|lu| Revised { row_count, lu })
+    }
+
+    fn column(&self, columns: &[SparseColumn], column: usize) -> Vec<f64> {
+        let mut a = vec![0f64; self.row_count];
+        for &(row, value) in columns[column].iter() {
+            a[row] = value;
+        }
+        self.lu.solve(&a)
+    }
+
+    fn row(&self, columns: &[SparseColumn], position: usize) -> Vec<f64> {
+        let mut e = vec![0f64; self.row_count];
+        e[position] = 1f64;
+        let rho = self.lu.solve_transposed(&e);
+        columns
+            .iter()
+            .map(|column| column.iter().map(|&(row, value)| rho[row] * value).sum())
+            .collect()
+    }
+
+    fn solve(&self, a: &[f64]) -> Vec<f64> {
+        self.lu.solve(a)
+    }
+
+    fn solve_transposed(&self, c: &[f64]) -> Vec<f64> {
+        self.lu.solve_transposed(c)
+    }
+
+    fn update(&mut self, position: usize, alpha: &[f64]) {
+        self.lu.update(position, alpha);
+    }
+
+    fn is_stale(&self) -> bool {
+        self.lu.update_count() >= REFACTORIZATION_INTERVAL
+    }
+}
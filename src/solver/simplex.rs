@@ -0,0 +1,895 @@
+//! The bounded primal simplex algorithm, shared by the backends.
+//!
+//! Every column of `[A | I]` (and every artificial column of phase one) has bounds `l <= x <= u`. Non-base variables
+//! rest at one of their bounds, free variables at zero, so bounds need no constraint rows of their own.
+
+use super::lu::SparseColumn;
+use super::pricing::Pricer;
+use super::ranging::{self, Movable, Ranging};
+use super::{
+    AntiCycling, BasisStatus, Initialization, Problem, Solution, SolveStatus, SolverOptions,
+};
+use super::{BIG_M_RATIO, BIG_M_WARNING};
+use std::collections::HashMap;
+
+/// The linear algebra of a backend: Systems of equations with the basis matrix `B`.
+pub trait Kernel: Sized {
+    /// Factorizes the basis matrix of the given columns (by basis position), `None` if it is singular up to `pivot_tolerance`.
+    fn factorize(
+        columns: &[SparseColumn],
+        basis: &[usize],
+        row_count: usize,
+        pivot_tolerance: f64,
+    ) -> Option<Self>;
+    /// Returns `B^-1 a` for a column `a`, indexed by basis positions.
+    fn column(&self, columns: &[SparseColumn], column: usize) -> Vec<f64>;
+    /// Returns the row of `B^-1 A` at a basis position, for every column.
+    fn row(&self, columns: &[SparseColumn], position: usize) -> Vec<f64>;
+    /// Solves `B x = a` (FTRAN), `a` indexed by rows, `x` by basis positions.
+    fn solve(&self, a: &[f64]) -> Vec<f64>;
+    /// Solves `B^T y = c` (BTRAN), `c` indexed by basis positions, `y` by rows.
+    fn solve_transposed(&self, c: &[f64]) -> Vec<f64>;
+    /// Replaces the basis column at `position`, `alpha` being `B^-1 a` for the new column `a`.
+    fn update(&mut self, position: usize, alpha: &[f64]);
+    /// Returns whether the basis should be factorized again.
+    fn is_stale(&self) -> bool;
+}
+
+/// The problem `max c x, A x = b, l <= x <= u` in the current phase.
+struct Simplex<'a, K> {
+    options: &'a SolverOptions,
+    /// Columns of `[A | I]`, followed by the artificial columns of phase one
+    columns: Vec<SparseColumn>,
+    b: Vec<f64>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    /// The objective of the current phase
+    c: Vec<f64>,
+    /// The values of all variables
+    x: Vec<f64>,
+    /// The objective value `c x`, updated on every step
+    value: f64,
+    basis: Vec<usize>,
+    /// The basis position of each base variable
+    position_of: Vec<Option<usize>>,
+    kernel: K,
+    iterations: usize,
+    /// Multipliers proving infeasibility, for the rows in their current orientation
+    certificate: Option<Vec<f64>>,
+    /// The direction of every variable along which the objective improves without limit
+    ray: Option<Vec<f64>>,
+}
+
+/// Returns the solution of an optimization stopped without an optimum.
+fn stopped(status: SolveStatus, iterations: usize) -> Solution {
+    let value = match status {
+        SolveStatus::Infeasible => -1.0 / 0.0,
+        SolveStatus::Unbounded => 1.0 / 0.0,
+        _ => f64::NAN,
+    };
+    Solution {
+        status,
+        x: Option::None,
+        value,
+        duals: Option::None,
+        reduced_costs: Option::None,
+        ranging: Option::None,
+        basis: Option::None,
+        iterations,
+        warnings: vec![],
+        farkas: Option::None,
+        ray: Option::None,
+    }
+}
+
+/// Optimizes a [`Problem`](struct.Problem.html) using the given [`Kernel`](trait.Kernel.html).
+///
+/// If the problem has a [`basis`](struct.Problem.html#structfield.basis) which is primal feasible, phase two starts
+/// from it. If it is dual feasible instead, the dual simplex algorithm starts from it. Otherwise the slack basis is the
+/// start, with artificial variables where it is infeasible.
+pub fn solve<K: Kernel>(problem: &Problem, options: &SolverOptions) -> Solution {
+    let tolerances = &options.tolerances;
+    if problem
+        .lower
+        .iter()
+        .zip(problem.upper.iter())
+        .any(|(&lower, &upper)| lower > upper + tolerances.primal_feasibility)
+    {
+        // No point is within the bounds, any multipliers prove it
+        return Solution {
+            farkas: Option::Some(vec![0f64; problem.rhs.len()]),
+            ..stopped(SolveStatus::Infeasible, 0)
+        };
+    }
+    if let Option::Some(basis) = &problem.basis {
+        let mut start = initial_values(problem);
+        for ((value, &status), &upper) in start.iter_mut().zip(basis).zip(problem.upper.iter()) {
+            if status == BasisStatus::AtUpper && upper.is_finite() {
+                *value = upper;
+            }
+        }
+        let (factors, artificial) = (
+            vec![1f64; problem.rhs.len()],
+            vec![false; problem.rhs.len()],
+        );
+        if let Option::Some(mut simplex) =
+            Simplex::<K>::new(problem, options, &factors, &artificial, start)
+        {
+            if simplex.crash(basis) {
+                if simplex.is_primal_feasible() {
+                    let status = simplex.run(&problem.objective, simplex.columns.len());
+                    return simplex.solution(status, problem);
+                }
+                simplex.c[..problem.variable_count()].copy_from_slice(&problem.objective);
+                simplex.value = simplex.objective_value();
+                if simplex.is_dual_feasible() {
+                    // Right sides or bounds changed, or rows were added: Re-optimize by the dual simplex algorithm
+                    let status = match simplex.dual() {
+                        SolveStatus::Optimal => simplex.primal(),
+                        status => status,
+                    };
+                    return simplex.solution(status, problem);
+                }
+            }
+        }
+    }
+    let start = initial_values(problem);
+    let slacks: Vec<f64> = (0..problem.rhs.len())
+        .map(|row| {
+            let activity: f64 = problem
+                .constraints
+                .row(row)
+                .map(|(column, value)| value * start[column])
+                .sum();
+            problem.rhs[row] - activity
+        })
+        .collect();
+    // Rows whose slack would be negative are negated and get an artificial variable, as do rows whose slack would
+    // exceed its range (equalities)
+    let factors: Vec<f64> = slacks
+        .iter()
+        .map(|&slack| {
+            if slack < -tolerances.primal_feasibility {
+                -1f64
+            } else {
+                1f64
+            }
+        })
+        .collect();
+    let artificial: Vec<bool> = slacks
+        .iter()
+        .zip(problem.range.iter())
+        .map(|(&slack, &range)| {
+            slack < -tolerances.primal_feasibility || slack > range + tolerances.primal_feasibility
+        })
+        .collect();
+    let position_b = problem.variable_count() + problem.rhs.len();
+    let mut solution = match Simplex::<K>::new(problem, options, &factors, &artificial, start) {
+        Option::Some(mut simplex) => {
+            let status = simplex.run(&problem.objective, position_b);
+            simplex.solution(status, problem)
+        }
+        Option::None => stopped(SolveStatus::NumericalFailure, 0),
+    };
+    if let Initialization::BigM(m) = options.initialization {
+        if artificial.contains(&true) && m < BIG_M_RATIO * largest_coefficient(problem) {
+            solution.warnings.push(BIG_M_WARNING);
+        }
+    }
+    solution
+}
+
+/// Returns the largest absolute value of the objective coefficients and the constraints' entries.
+fn largest_coefficient(problem: &Problem) -> f64 {
+    let entries = (0..problem.rhs.len()).flat_map(|row| problem.constraints.row(row));
+    problem
+        .objective
+        .iter()
+        .cloned()
+        .chain(entries.map(|(_, value)| value))
+        .fold(0f64, |largest, value| largest.max(value.abs()))
+}
+
+/// Returns the values of the non-base variables in the slack basis: A finite bound, zero for free variables.
+fn initial_values(problem: &Problem) -> Vec<f64> {
+    problem
+        .lower
+        .iter()
+        .zip(problem.upper.iter())
+        .map(|(&lower, &upper)| {
+            if lower.is_finite() {
+                lower
+            } else if upper.is_finite() {
+                upper
+            } else {
+                0f64
+            }
+        })
+        .collect()
+}
+
+impl<'a, K: Kernel> Simplex<'a, K> {
+    /// Sets up the slack basis for the rows multiplied by `factors`, the rows marked in `artificial` getting an
+    /// artificial variable instead. `None` if the basis can not be factorized.
+    fn new(
+        problem: &Problem,
+        options: &'a SolverOptions,
+        factors: &[f64],
+        artificial: &[bool],
+        start: Vec<f64>,
+    ) -> Option<Self> {
+        let variable_count = problem.variable_count();
+        let row_count = problem.rhs.len();
+        let transposed = problem.constraints.transpose(variable_count);
+        let mut columns: Vec<SparseColumn> = (0..variable_count)
+            .map(|column| {
+                transposed
+                    .row(column)
+                    .map(|(row, value)| (row, factors[row] * value))
+                    .collect()
+            })
+            .collect();
+        let mut basis: Vec<usize> = Vec::with_capacity(row_count);
+        for (row, &factor) in factors.iter().enumerate() {
+            basis.push(columns.len());
+            columns.push(vec![(row, factor)]);
+        }
+        for (row, &artificial) in artificial.iter().enumerate() {
+            if artificial {
+                basis[row] = columns.len();
+                columns.push(vec![(row, 1f64)]);
+            }
+        }
+        let b: Vec<f64> = problem
+            .rhs
+            .iter()
+            .zip(factors.iter())
+            .map(|(&b, &factor)| factor * b)
+            .collect();
+        let mut lower = problem.lower.clone();
+        lower.resize(columns.len(), 0f64);
+        let mut upper = problem.upper.clone();
+        upper.extend(problem.range.iter());
+        upper.resize(columns.len(), 1.0 / 0.0);
+        let mut x = start;
+        x.resize(columns.len(), 0f64);
+        let mut position_of: Vec<Option<usize>> = vec![Option::None; columns.len()];
+        for (position, &variable) in basis.iter().enumerate() {
+            position_of[variable] = Option::Some(position);
+        }
+        // The slack and artificial columns form an identity matrix
+        let kernel = K::factorize(&columns, &basis, row_count, options.tolerances.pivot)?;
+        let mut simplex = Simplex {
+            options,
+            c: vec![0f64; columns.len()],
+            columns,
+            b,
+            lower,
+            upper,
+            x,
+            value: 0f64,
+            basis,
+            position_of,
+            kernel,
+            iterations: 0,
+            certificate: Option::None,
+            ray: Option::None,
+        };
+        simplex.compute_basic_values();
+        Option::Some(simplex)
+    }
+
+    /// Returns the [`Solution`](struct.Solution.html) for the status the algorithm stopped with.
+    fn solution(&self, status: SolveStatus, problem: &Problem) -> Solution {
+        let variable_count = problem.variable_count();
+        let row_count = problem.rhs.len();
+        if status != SolveStatus::Optimal {
+            // Multipliers of the negated rows apply to the rows negated
+            let farkas = self.certificate.as_ref().map(|y| {
+                (0..row_count)
+                    .map(|row| self.dot(y, variable_count + row))
+                    .collect()
+            });
+            let ray = self.ray.as_ref().map(|ray| ray[..variable_count].to_vec());
+            return Solution {
+                farkas,
+                ray,
+                ..stopped(status, self.iterations)
+            };
+        }
+        let x: HashMap<usize, f64> = (0..variable_count)
+            .map(|variable| (variable, self.value(variable)))
+            .collect();
+        let y = self.dual_solution();
+        // The dual value of a (not negated) row is the one of its slack column
+        let duals = (0..row_count)
+            .map(|row| self.dot(&y, variable_count + row))
+            .collect();
+        let reduced_costs = self.reduced_costs(&y);
+        let ranging = if self.options.sensitivity {
+            Option::Some(self.ranging(problem, &reduced_costs))
+        } else {
+            Option::None
+        };
+        let basis = (0..variable_count + row_count)
+            .map(|column| match self.position_of[column] {
+                Option::Some(_) => BasisStatus::Basic,
+                Option::None if self.x[column] == self.upper[column] => BasisStatus::AtUpper,
+                Option::None => BasisStatus::AtLower,
+            })
+            .collect();
+        Solution {
+            status,
+            x: Option::Some(x),
+            value: self.value,
+            duals: Option::Some(duals),
+            reduced_costs: Option::Some(reduced_costs[..variable_count].to_vec()),
+            ranging,
+            basis: Option::Some(basis),
+            iterations: self.iterations,
+            warnings: vec![],
+            farkas: Option::None,
+            ray: Option::None,
+        }
+    }
+
+    /// Pivots the base variables of a given basis into the slack basis, replacing slack variables not in the given
+    /// basis as far as the columns are linearly independent. `false` if the basis can not be factorized.
+    fn crash(&mut self, basis: &[BasisStatus]) -> bool {
+        let variable_count = basis.len() - self.b.len();
+        let is_basic = |column: usize| basis[column] == BasisStatus::Basic;
+        for column in (0..variable_count).filter(|&column| is_basic(column)) {
+            let alpha = self.kernel.column(&self.columns, column);
+            let mut pivot_position: (usize, f64) = (0, 0f64);
+            for (position, &a) in alpha.iter().enumerate() {
+                let variable = self.basis[position];
+                if variable >= variable_count && !is_basic(variable) && a.abs() > pivot_position.1 {
+                    pivot_position = (position, a.abs());
+                }
+            }
+            if pivot_position.1 <= self.options.tolerances.pivot {
+                continue;
+            }
+            let (position, leaving) = (pivot_position.0, self.basis[pivot_position.0]);
+            self.x[leaving] = match basis[leaving] {
+                BasisStatus::AtUpper => self.upper[leaving],
+                _ => self.lower[leaving],
+            };
+            self.basis[position] = column;
+            self.position_of[leaving] = Option::None;
+            self.position_of[column] = Option::Some(position);
+            self.kernel.update(position, &alpha);
+        }
+        self.refactorize()
+    }
+
+    /// Returns whether the base variables are within their bounds.
+    fn is_primal_feasible(&self) -> bool {
+        let tolerance = self.options.tolerances.primal_feasibility;
+        self.basis.iter().all(|&variable| {
+            self.x[variable] >= self.lower[variable] - tolerance
+                && self.x[variable] <= self.upper[variable] + tolerance
+        })
+    }
+
+    fn run(&mut self, objective: &[f64], position_b: usize) -> SolveStatus {
+        if position_b < self.columns.len() {
+            let tolerance = self.options.tolerances.primal_feasibility;
+            match self.options.initialization {
+                Initialization::TwoPhases => {
+                    // Phase one: maximize the negated sum of the artificial variables
+                    for column in position_b..self.columns.len() {
+                        self.c[column] = -1f64;
+                    }
+                    self.value = self.objective_value();
+                    match self.primal() {
+                        SolveStatus::Optimal => (),
+                        // The phase one objective is bounded by zero
+                        SolveStatus::Unbounded => return SolveStatus::NumericalFailure,
+                        status => return status,
+                    }
+                    // Check if model is feasable, an empty feasible region has the optimum -inf
+                    if self.value.abs() > tolerance {
+                        // The phase one duals bound the sum of the artificial variables away from zero
+                        self.certificate = Option::Some(self.dual_solution());
+                        return SolveStatus::Infeasible;
+                    }
+                }
+                Initialization::BigM(m) => {
+                    // The objective, every artificial variable costing `M`
+                    self.c[..objective.len()].copy_from_slice(objective);
+                    for column in position_b..self.columns.len() {
+                        self.c[column] = -m;
+                    }
+                    self.value = self.objective_value();
+                    let status = self.primal();
+                    let artificial = match status {
+                        SolveStatus::Optimal => self.x[position_b..].iter().any(|&x| x > tolerance),
+                        // A ray along artificial variables does not prove the model unbounded
+                        SolveStatus::Unbounded => (position_b..self.columns.len()).any(|column| {
+                            self.position_of[column].is_some()
+                                || self.x[column] > tolerance
+                                || self.ray.as_ref().is_some_and(|ray| ray[column] != 0f64)
+                        }),
+                        status => return status,
+                    };
+                    if !artificial && status == SolveStatus::Unbounded {
+                        return status;
+                    }
+                    if artificial {
+                        // Phase one from here decides, `M` may have been too small
+                        self.ray = Option::None;
+                        self.c.iter_mut().for_each(|c| *c = 0f64);
+                        for column in position_b..self.columns.len() {
+                            self.c[column] = -1f64;
+                        }
+                        self.value = self.objective_value();
+                        match self.primal() {
+                            SolveStatus::Optimal => (),
+                            SolveStatus::Unbounded => return SolveStatus::NumericalFailure,
+                            status => return status,
+                        }
+                        if self.value.abs() > tolerance {
+                            self.certificate = Option::Some(self.dual_solution());
+                            return SolveStatus::Infeasible;
+                        }
+                    }
+                }
+            }
+            self.drive_out_artificial_variables(position_b);
+            // Artificial variables are fixed at zero, those left in the basis belong to redundant rows
+            for column in position_b..self.columns.len() {
+                self.x[column] = 0f64;
+                self.upper[column] = 0f64;
+                self.c[column] = 0f64;
+            }
+        }
+        // Phase two
+        self.c[..objective.len()].copy_from_slice(objective);
+        self.value = self.objective_value();
+        self.primal()
+    }
+
+    fn objective_value(&self) -> f64 {
+        self.c.iter().zip(self.x.iter()).map(|(&c, &x)| c * x).sum()
+    }
+
+    /// Returns a variable's value, violations of its bounds within the primal feasibility tolerance removed.
+    fn value(&self, variable: usize) -> f64 {
+        let tolerance = self.options.tolerances.primal_feasibility;
+        let (lower, upper) = (self.lower[variable], self.upper[variable]);
+        let value = self.x[variable];
+        if value < lower && value >= lower - tolerance {
+            lower
+        } else if value > upper && value <= upper + tolerance {
+            upper
+        } else {
+            value
+        }
+    }
+
+    /// Computes the base variables' values from the non-base variables' values.
+    fn compute_basic_values(&mut self) {
+        let mut residual = self.b.clone();
+        for (column, entries) in self.columns.iter().enumerate() {
+            if self.position_of[column].is_none() && self.x[column] != 0f64 {
+                for &(row, value) in entries.iter() {
+                    residual[row] -= value * self.x[column];
+                }
+            }
+        }
+        for (&variable, value) in self.basis.iter().zip(self.kernel.solve(&residual)) {
+            self.x[variable] = value;
+        }
+    }
+
+    /// Returns `y` solving `B^T y = c_B`.
+    fn dual_solution(&self) -> Vec<f64> {
+        let c_b: Vec<f64> = self
+            .basis
+            .iter()
+            .map(|&variable| self.c[variable])
+            .collect();
+        self.kernel.solve_transposed(&c_b)
+    }
+
+    fn dot(&self, y: &[f64], column: usize) -> f64 {
+        self.columns[column]
+            .iter()
+            .map(|&(row, value)| y[row] * value)
+            .sum()
+    }
+
+    /// Returns the reduced costs for the dual solution `y`, zero for base variables.
+    fn reduced_costs(&self, y: &[f64]) -> Vec<f64> {
+        (0..self.columns.len())
+            .map(|column| match self.position_of[column] {
+                Option::Some(_) => 0f64,
+                Option::None => self.c[column] - self.dot(y, column),
+            })
+            .collect()
+    }
+
+    /// Returns the directions a non-base variable can move in from its value.
+    fn movable(&self, column: usize) -> Movable {
+        Movable {
+            increase: self.x[column] < self.upper[column],
+            decrease: self.x[column] > self.lower[column],
+        }
+    }
+
+    /// Returns the objective's improvement per unit of moving a non-base variable, zero if it can not improve it.
+    fn improvement(&self, column: usize, reduced_cost: f64) -> f64 {
+        let movable = self.movable(column);
+        if reduced_cost > 0f64 && movable.increase {
+            reduced_cost
+        } else if reduced_cost < 0f64 && movable.decrease {
+            -reduced_cost
+        } else {
+            0f64
+        }
+    }
+
+    fn primal(&mut self) -> SolveStatus {
+        let tolerances = &self.options.tolerances;
+        let initial_basis = self.basis.clone();
+        let mut pricer = Pricer::new(self.options, self.columns.len());
+        loop {
+            let reduced_costs = self.reduced_costs(&self.dual_solution());
+            let improvements: Vec<f64> = reduced_costs
+                .iter()
+                .enumerate()
+                .map(|(column, &d)| match self.position_of[column] {
+                    Option::Some(_) => 0f64,
+                    Option::None => self.improvement(column, d),
+                })
+                .collect();
+            if !improvements
+                .iter()
+                .any(|&improvement| improvement > tolerances.dual_feasibility)
+            {
+                return SolveStatus::Optimal;
+            }
+            if let Option::Some(iteration_limit) = self.options.iteration_limit {
+                if self.iterations >= iteration_limit {
+                    return SolveStatus::IterationLimit;
+                }
+            }
+            let entering = pricer.choose(&improvements, |column| {
+                self.kernel
+                    .column(&self.columns, column)
+                    .iter()
+                    .fold(1f64, |acc, a| acc + a * a)
+            });
+            let direction = reduced_costs[entering].signum();
+            let alpha = self.kernel.column(&self.columns, entering);
+            let (step, leaving) = match self.ratio_test(entering, direction, &alpha, &initial_basis)
+            {
+                Option::Some(ratio) => ratio,
+                Option::None => {
+                    // The entering variable moves without limit, the base variables along
+                    let mut ray = vec![0f64; self.columns.len()];
+                    ray[entering] = direction;
+                    for (&variable, &a) in self.basis.iter().zip(alpha.iter()) {
+                        ray[variable] = -direction * a;
+                    }
+                    self.ray = Option::Some(ray);
+                    return SolveStatus::Unbounded;
+                }
+            };
+            self.value += improvements[entering] * step;
+            self.x[entering] += direction * step;
+            for (&variable, &a) in self.basis.iter().zip(alpha.iter()) {
+                self.x[variable] -= direction * step * a;
+            }
+            // Without a leaving variable, the entering variable moved from one bound to the other
+            if let Option::Some((position, bound)) = leaving {
+                let leaving = self.basis[position];
+                if pricer.needs_pivot_row() {
+                    let mut row = self.kernel.row(&self.columns, position);
+                    row[entering] = alpha[position];
+                    pricer.update(&row, entering, leaving);
+                }
+                self.x[leaving] = bound;
+                self.basis[position] = entering;
+                self.position_of[leaving] = Option::None;
+                self.position_of[entering] = Option::Some(position);
+                self.kernel.update(position, &alpha);
+            }
+            self.iterations += 1;
+            if self.kernel.is_stale() && !self.refactorize() {
+                return SolveStatus::NumericalFailure;
+            }
+            if !self.x.iter().all(|x| x.is_finite()) {
+                return SolveStatus::NumericalFailure;
+            }
+        }
+    }
+
+    /// Returns whether no non-base variable can improve the objective (the basis is dual feasible).
+    fn is_dual_feasible(&self) -> bool {
+        let reduced_costs = self.reduced_costs(&self.dual_solution());
+        reduced_costs.iter().enumerate().all(|(column, &d)| {
+            self.position_of[column].is_some()
+                || self.improvement(column, d) <= self.options.tolerances.dual_feasibility
+        })
+    }
+
+    /// The dual simplex algorithm: Starting from a dual feasible basis, base variables violating their bounds leave
+    /// the basis at the violated bound, keeping the basis dual feasible.
+    fn dual(&mut self) -> SolveStatus {
+        let tolerances = &self.options.tolerances;
+        loop {
+            // The leaving variable violates its bounds the most (Bland's rule: has the smallest index)
+            let mut leaving: Option<(usize, f64)> = Option::None;
+            for (position, &variable) in self.basis.iter().enumerate() {
+                let x = self.x[variable];
+                let violation = f64::max(self.lower[variable] - x, x - self.upper[variable]);
+                if violation <= tolerances.primal_feasibility {
+                    continue;
+                }
+                let replace = match (leaving, self.options.anti_cycling) {
+                    (Option::None, _) => true,
+                    (Option::Some((other, _)), AntiCycling::Bland) => variable < self.basis[other],
+                    (Option::Some((_, max_violation)), _) => violation > max_violation,
+                };
+                if replace {
+                    leaving = Option::Some((position, violation));
+                }
+            }
+            let position = match leaving {
+                Option::Some((position, _)) => position,
+                Option::None => {
+                    // Values computed from the final basis, without the rounding errors of the steps
+                    self.compute_basic_values();
+                    self.value = self.objective_value();
+                    return SolveStatus::Optimal;
+                }
+            };
+            if let Option::Some(iteration_limit) = self.options.iteration_limit {
+                if self.iterations >= iteration_limit {
+                    return SolveStatus::IterationLimit;
+                }
+            }
+            let leaving = self.basis[position];
+            let (bound, increase) = if self.x[leaving] < self.lower[leaving] {
+                (self.lower[leaving], 1f64)
+            } else {
+                (self.upper[leaving], -1f64)
+            };
+            // The entering variable keeps the reduced costs' signs: the smallest ratio of reduced cost and pivot row
+            // entry among the variables moving the leaving one towards its bound
+            let reduced_costs = self.reduced_costs(&self.dual_solution());
+            let row = self.kernel.row(&self.columns, position);
+            let mut entering: Option<(usize, f64)> = Option::None;
+            for (column, &a) in row.iter().enumerate() {
+                if self.position_of[column].is_some() || a.abs() <= tolerances.pivot {
+                    continue;
+                }
+                // The leaving variable changes by `-a` per unit of the entering variable
+                let movable = self.movable(column);
+                if !((movable.increase && -a * increase > 0f64)
+                    || (movable.decrease && a * increase > 0f64))
+                {
+                    continue;
+                }
+                let ratio = reduced_costs[column].abs() / a.abs();
+                let replace = match entering {
+                    Option::None => true,
+                    Option::Some((_, min_ratio)) if ratio < min_ratio => true,
+                    Option::Some((other, min_ratio)) if ratio == min_ratio => {
+                        match self.options.anti_cycling {
+                            AntiCycling::Bland => false,
+                            AntiCycling::Lexicographic => a.abs() > row[other].abs(),
+                        }
+                    }
+                    _ => false,
+                };
+                if replace {
+                    entering = Option::Some((column, ratio));
+                }
+            }
+            // No variable can repair the row, its bounds can not be met: The row of the basis inverse proves it
+            let entering = match entering {
+                Option::Some((column, _)) => column,
+                Option::None => {
+                    let rho = self.inverse_row(position);
+                    self.certificate = Option::Some(rho.iter().map(|rho| increase * rho).collect());
+                    return SolveStatus::Infeasible;
+                }
+            };
+            let alpha = self.kernel.column(&self.columns, entering);
+            let step = (self.x[leaving] - bound) / alpha[position];
+            self.x[entering] += step;
+            for (&variable, &a) in self.basis.iter().zip(alpha.iter()) {
+                self.x[variable] -= step * a;
+            }
+            self.x[leaving] = bound;
+            self.basis[position] = entering;
+            self.position_of[leaving] = Option::None;
+            self.position_of[entering] = Option::Some(position);
+            self.kernel.update(position, &alpha);
+            self.value += reduced_costs[entering] * step;
+            self.iterations += 1;
+            if self.kernel.is_stale() && !self.refactorize() {
+                return SolveStatus::NumericalFailure;
+            }
+            if !self.x.iter().all(|x| x.is_finite()) {
+                return SolveStatus::NumericalFailure;
+            }
+        }
+    }
+
+    /// Factorizes the basis and recomputes the base variables' values, `false` if the basis is singular.
+    fn refactorize(&mut self) -> bool {
+        match K::factorize(
+            &self.columns,
+            &self.basis,
+            self.b.len(),
+            self.options.tolerances.pivot,
+        ) {
+            Option::Some(kernel) => self.kernel = kernel,
+            Option::None => return false,
+        }
+        self.compute_basic_values();
+        self.value = self.objective_value();
+        true
+    }
+
+    /// Returns the step length of the entering variable moving in `direction` and the leaving basis position with the
+    /// bound its variable reaches. No variable leaves if the entering variable reaches its other bound first.
+    /// `None` if the objective is unbounded.
+    fn ratio_test(
+        &self,
+        entering: usize,
+        direction: f64,
+        alpha: &[f64],
+        initial_basis: &[usize],
+    ) -> Option<(f64, Option<(usize, f64)>)> {
+        let mut min_row: Option<(usize, f64, f64)> = Option::None;
+        for (position, &a) in alpha.iter().enumerate() {
+            if a.abs() <= self.options.tolerances.pivot {
+                continue;
+            }
+            let variable = self.basis[position];
+            // The change of the base variable per unit step
+            let rate = -direction * a;
+            let (ratio, bound) = if rate < 0f64 && self.lower[variable].is_finite() {
+                let bound = self.lower[variable];
+                ((self.x[variable] - bound).max(0f64) / -rate, bound)
+            } else if rate > 0f64 && self.upper[variable].is_finite() {
+                let bound = self.upper[variable];
+                ((bound - self.x[variable]).max(0f64) / rate, bound)
+            } else {
+                continue;
+            };
+            let replace = match min_row {
+                Option::None => true,
+                Option::Some((_, min_ratio, _)) if ratio < min_ratio => true,
+                Option::Some((min_position, min_ratio, _)) if ratio == min_ratio => {
+                    match self.options.anti_cycling {
+                        AntiCycling::Bland => self.basis[position] < self.basis[min_position],
+                        AntiCycling::Lexicographic => self.lexicographically_smaller(
+                            (position, direction * a),
+                            (min_position, direction * alpha[min_position]),
+                            initial_basis,
+                        ),
+                    }
+                }
+                _ => false,
+            };
+            if replace {
+                min_row = Option::Some((position, ratio, bound));
+            }
+        }
+        let flip = self.upper[entering] - self.lower[entering];
+        match min_row {
+            Option::Some((_, ratio, _)) if flip <= ratio => Option::Some((flip, Option::None)),
+            Option::Some((position, ratio, bound)) => {
+                Option::Some((ratio, Option::Some((position, bound))))
+            }
+            Option::None if flip.is_finite() => Option::Some((flip, Option::None)),
+            Option::None => Option::None,
+        }
+    }
+
+    /// Compares two rows of the tableau, scaled by their entry in the pivot column (times the direction),
+    /// over the columns of the initial basis.
+    fn lexicographically_smaller(
+        &self,
+        (position, a): (usize, f64),
+        (other_position, other_a): (usize, f64),
+        initial_basis: &[usize],
+    ) -> bool {
+        let rho = self.inverse_row(position);
+        let other_rho = self.inverse_row(other_position);
+        for &column in initial_basis {
+            let value = self.dot(&rho, column) / a;
+            let other_value = self.dot(&other_rho, column) / other_a;
+            if value != other_value {
+                return value < other_value;
+            }
+        }
+        false
+    }
+
+    /// Returns the row of the basis matrix's inverse at the given basis position.
+    fn inverse_row(&self, position: usize) -> Vec<f64> {
+        let mut e = vec![0f64; self.b.len()];
+        e[position] = 1f64;
+        self.kernel.solve_transposed(&e)
+    }
+
+    /// Replaces the artificial variables left in the basis (at zero) where the row has another pivot element.
+    fn drive_out_artificial_variables(&mut self, position_b: usize) {
+        for position in 0..self.basis.len() {
+            let artificial = self.basis[position];
+            if artificial < position_b {
+                continue;
+            }
+            let row = self.kernel.row(&self.columns, position);
+            let mut pivot_column: (usize, f64) = (0, 0f64);
+            for (column, &value) in row[..position_b].iter().enumerate() {
+                if value.abs() > pivot_column.1 && self.position_of[column].is_none() {
+                    pivot_column = (column, value.abs());
+                }
+            }
+            if pivot_column.1 <= self.options.tolerances.pivot {
+                continue;
+            }
+            // A degenerate pivot, the entering variable keeps its value
+            let alpha = self.kernel.column(&self.columns, pivot_column.0);
+            self.x[artificial] = 0f64;
+            self.basis[position] = pivot_column.0;
+            self.position_of[artificial] = Option::None;
+            self.position_of[pivot_column.0] = Option::Some(position);
+            self.kernel.update(position, &alpha);
+        }
+    }
+
+    /// Ranges the objective coefficients and right sides of the optimal basis.
+    fn ranging(&self, problem: &Problem, reduced_costs: &[f64]) -> Ranging {
+        let variable_count = problem.variable_count();
+        let tolerances = &self.options.tolerances;
+        let cost = (0..variable_count)
+            .map(|variable| {
+                let row = self.position_of[variable].map(|position| {
+                    let row = self.kernel.row(&self.columns, position);
+                    (0..self.columns.len())
+                        .filter(|&column| self.position_of[column].is_none())
+                        .map(|column| (reduced_costs[column], row[column], self.movable(column)))
+                        .collect::<Vec<(f64, f64, Movable)>>()
+                        .into_iter()
+                });
+                ranging::cost_interval(
+                    problem.objective[variable],
+                    reduced_costs[variable],
+                    self.movable(variable),
+                    row,
+                    tolerances,
+                )
+            })
+            .collect();
+        let bounds: Vec<(f64, f64, f64)> = self
+            .basis
+            .iter()
+            .map(|&variable| (self.x[variable], self.lower[variable], self.upper[variable]))
+            .collect();
+        let rhs = problem
+            .ranged_rhs
+            .iter()
+            .map(|direction| {
+                // The slack columns hold the change of the base variables per unit of their row's right side
+                let mut beta = vec![0f64; self.b.len()];
+                for &(row, factor) in direction {
+                    let alpha = self.kernel.column(&self.columns, variable_count + row);
+                    for (value, alpha) in beta.iter_mut().zip(alpha) {
+                        *value += factor * alpha;
+                    }
+                }
+                ranging::rhs_interval(&bounds, &beta, tolerances)
+            })
+            .collect();
+        Ranging { cost, rhs }
+    }
+}
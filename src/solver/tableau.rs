@@ -0,0 +1,114 @@
+//! The dense tableau: `B^-1` applied to every column, updated completely on every pivot.
+
+use super::lu::SparseColumn;
+use super::simplex::Kernel;
+
+/// The rows of `[B^-1 A | B^-1]`, by basis position.
+pub struct Tableau {
+    column_count: usize,
+    rows: Vec<Vec<f64>>,
+}
+
+impl Tableau {
+    /// Pivots on the given row, `alpha` being the pivot column.
+    fn pivot(&mut self, pivot_row: usize, alpha: &[f64]) {
+        let pivot = alpha[pivot_row];
+        self.rows[pivot_row].iter_mut().for_each(|x| *x /= pivot);
+        let (before, rest) = self.rows.split_at_mut(pivot_row);
+        let (row, after) = rest.split_first_mut().unwrap();
+        for (other, &a) in before
+            .iter_mut()
+            .zip(alpha.iter())
+            .chain(after.iter_mut().zip(alpha[pivot_row + 1..].iter()))
+        {
+            if a != 0f64 {
+                for (x, &value) in other.iter_mut().zip(row.iter()) {
+                    *x -= a * value;
+                }
+            }
+        }
+    }
+}
+
+impl Kernel for Tableau {
+    fn factorize(
+        columns: &[SparseColumn],
+        basis: &[usize],
+        row_count: usize,
+        pivot_tolerance: f64,
+    ) -> Option<Self> {
+        let column_count = columns.len();
+        let mut rows = vec![vec![0f64; column_count + row_count]; row_count];
+        for (column, entries) in columns.iter().enumerate() {
+            for &(row, value) in entries.iter() {
+                rows[row][column] = value;
+            }
+        }
+        for (row, values) in rows.iter_mut().enumerate() {
+            values[column_count + row] = 1f64;
+        }
+        let mut tableau = Tableau { column_count, rows };
+        // Gauss-Jordan elimination, choosing the largest pivot element among the remaining rows
+        let mut row_of: Vec<usize> = Vec::with_capacity(row_count);
+        let mut pivoted = vec![false; row_count];
+        for &variable in basis {
+            let alpha: Vec<f64> = tableau.rows.iter().map(|row| row[variable]).collect();
+            let pivot_row = (0..row_count)
+                .filter(|&row| !pivoted[row])
+                .max_by(|&row, &other| alpha[row].abs().total_cmp(&alpha[other].abs()))?;
+            if alpha[pivot_row].abs() <= pivot_tolerance {
+                return Option::None;
+            }
+            tableau.pivot(pivot_row, &alpha);
+            pivoted[pivot_row] = true;
+            row_of.push(pivot_row);
+        }
+        let mut rows: Vec<Option<Vec<f64>>> = tableau.rows.into_iter().map(Option::Some).collect();
+        tableau.rows = row_of
+            .iter()
+            .map(|&row| rows[row].take().unwrap())
+            .collect();
+        Option::Some(tableau)
+    }
+
+    fn column(&self, _: &[SparseColumn], column: usize) -> Vec<f64> {
+        self.rows.iter().map(|row| row[column]).collect()
+    }
+
+    fn row(&self, _: &[SparseColumn], position: usize) -> Vec<f64> {
+        self.rows[position][..self.column_count].to_vec()
+    }
+
+    fn solve(&self, a: &[f64]) -> Vec<f64> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row[self.column_count..]
+                    .iter()
+                    .zip(a.iter())
+                    .map(|(inverse, a)| inverse * a)
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn solve_transposed(&self, c: &[f64]) -> Vec<f64> {
+        let mut y = vec![0f64; self.rows.len()];
+        for (row, &c) in self.rows.iter().zip(c.iter()) {
+            if c != 0f64 {
+                for (value, inverse) in y.iter_mut().zip(row[self.column_count..].iter()) {
+                    *value += c * inverse;
+                }
+            }
+        }
+        y
+    }
+
+    fn update(&mut self, position: usize, alpha: &[f64]) {
+        self.pivot(position, alpha);
+    }
+
+    fn is_stale(&self) -> bool {
+        false
+    }
+}
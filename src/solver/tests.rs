@@ -1,3 +1,4 @@
+use super::simplex::Kernel;
 use super::*;
 use std::collections::HashMap;
 
@@ -35,38 +36,547 @@ fn tableaus() -> [Vec<Vec<f64>>; 4] {
     ]
 }
 
+fn solve(tableau: &[Vec<f64>]) -> (SolveStatus, Option<HashMap<usize, f64>>, f64) {
+    let solution = optimize(tableau, &SolverOptions::default());
+    (solution.status, solution.x, solution.value)
+}
+
 #[test]
 fn solve_1() {
-    let mut tableaus = tableaus();
+    let tableaus = tableaus();
     let mut solution = HashMap::new();
     solution.insert(0, 3.6);
     solution.insert(1, 0.4);
     assert_eq!(
-        (Option::Some(solution), 7.6),
-        optimize(&mut tableaus[0], Option::None)
+        (SolveStatus::Optimal, Option::Some(solution), 7.6),
+        solve(&tableaus[0])
     );
     let mut solution = HashMap::new();
     solution.insert(0, 20.0);
     solution.insert(1, 17.0);
     assert_eq!(
-        (Option::Some(solution), 94.0),
-        optimize(&mut tableaus[1], Option::None)
+        (SolveStatus::Optimal, Option::Some(solution), 94.0),
+        solve(&tableaus[1])
     );
     assert_eq!(
-        (Option::None, 1.0 / 0.0),
-        optimize(&mut tableaus[2], Option::None)
+        (SolveStatus::Unbounded, Option::None, 1.0 / 0.0),
+        solve(&tableaus[2])
     );
 }
 
 #[test]
 fn solve_two_phases() {
-    let mut tableaus = tableaus();
+    let tableaus = tableaus();
     let mut solution = HashMap::new();
     solution.insert(0, 10.0);
     solution.insert(1, 10.0);
     solution.insert(2, 20.0);
     assert_eq!(
-        (Option::Some(solution), 70.0),
-        optimize(&mut tableaus[3], Option::None)
+        (SolveStatus::Optimal, Option::Some(solution), 70.0),
+        solve(&tableaus[3])
+    );
+}
+
+#[test]
+fn solve_infeasible() {
+    // x1 <= 1, x1 >= 2
+    let tableau = vec![
+        vec![1.0, 0.0, 0.0, 0.0],
+        vec![1.0, 1.0, 0.0, 1.0],
+        vec![-1.0, 0.0, 1.0, -2.0],
+    ];
+    assert_eq!(
+        (SolveStatus::Infeasible, Option::None, -1.0 / 0.0),
+        solve(&tableau)
     );
 }
+
+#[test]
+fn big_m() {
+    let infeasible = vec![
+        vec![1.0, 0.0, 0.0, 0.0],
+        vec![1.0, 1.0, 0.0, 1.0],
+        vec![-1.0, 0.0, 1.0, -2.0],
+    ];
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            initialization: Initialization::BigM(1e6),
+            ..SolverOptions::default()
+        };
+        let solution = optimize(&tableaus()[3], &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 70.0).abs() < 1e-9);
+        let x = solution.x.unwrap();
+        for (variable, &value) in [10.0, 10.0, 20.0].iter().enumerate() {
+            assert!((x[&variable] - value).abs() < 1e-9);
+        }
+        assert!(solution.warnings.is_empty());
+        let solution = optimize(&infeasible, &options);
+        assert_eq!(SolveStatus::Infeasible, solution.status);
+        // Equality rows start with an artificial variable
+        let mut problem = Problem::from_tableau(&tableaus()[0]);
+        problem.rhs[1] = 1.0;
+        problem.range[1] = 0.0;
+        let solution = super::solve(&problem, &options);
+        assert!((solution.value - 2.0).abs() < 1e-9);
+    }
+    // Too small relative to the coefficients, only if artificial variables are needed
+    let options = SolverOptions {
+        initialization: Initialization::BigM(10.0),
+        ..SolverOptions::default()
+    };
+    let solution = optimize(&tableaus()[3], &options);
+    assert_eq!(vec![BIG_M_WARNING], solution.warnings);
+    let solution = optimize(&tableaus()[0], &options);
+    assert!(solution.warnings.is_empty());
+}
+
+#[test]
+fn iteration_limit() {
+    let tableaus = tableaus();
+    let options = SolverOptions {
+        iteration_limit: Option::Some(1),
+        ..SolverOptions::default()
+    };
+    let solution = optimize(&tableaus[0], &options);
+    assert_eq!(SolveStatus::IterationLimit, solution.status);
+    assert_eq!(Option::None, solution.x);
+    assert_eq!(1, solution.iterations);
+}
+
+/// Beale's example, cycling with Dantzig's rule if ties are broken by the first row.
+fn beale() -> Vec<Vec<f64>> {
+    // x* = (x1,x2,x3,x4) = (1, 0, 1, 0)
+    // opt: 1.25
+    vec![
+        vec![0.75, -20.0, 0.5, -6.0, 0.0, 0.0, 0.0, 0.0],
+        vec![0.25, -8.0, -1.0, 9.0, 1.0, 0.0, 0.0, 0.0],
+        vec![0.5, -12.0, -0.5, 3.0, 0.0, 1.0, 0.0, 0.0],
+        vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0],
+    ]
+}
+
+#[test]
+fn anti_cycling() {
+    for &anti_cycling in &[AntiCycling::Bland, AntiCycling::Lexicographic] {
+        let options = SolverOptions {
+            iteration_limit: Option::Some(100),
+            anti_cycling,
+            ..SolverOptions::default()
+        };
+        let solution = optimize(&beale(), &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 1.25).abs() < 1e-9);
+        let x = solution.x.unwrap();
+        assert!((x[&0] - 1.0).abs() < 1e-9);
+        assert_eq!(0.0, x[&1]);
+        assert!((x[&2] - 1.0).abs() < 1e-9);
+        assert_eq!(0.0, x[&3]);
+    }
+}
+
+#[test]
+fn pricing() {
+    for &pricing in &[
+        Pricing::Dantzig,
+        Pricing::SteepestEdge,
+        Pricing::Devex,
+        Pricing::Partial(1),
+        Pricing::Partial(2),
+    ] {
+        let options = SolverOptions {
+            pricing,
+            ..SolverOptions::default()
+        };
+        let tableaus = tableaus();
+        let solution = optimize(&tableaus[0], &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 7.6).abs() < 1e-9);
+        let solution = optimize(&tableaus[1], &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 94.0).abs() < 1e-9);
+        let solution = optimize(&tableaus[2], &options);
+        assert_eq!(SolveStatus::Unbounded, solution.status);
+        let solution = optimize(&tableaus[3], &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 70.0).abs() < 1e-9);
+        let solution = optimize(&beale(), &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 1.25).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn tolerances() {
+    // The reduced cost of x1 is a rounding error
+    let tableau = vec![vec![1e-12, -1.0, 0.0, 0.0], vec![1.0, 1.0, 1.0, 5.0]];
+    let solution = optimize(&tableau, &SolverOptions::default());
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(0, solution.iterations);
+    let options = SolverOptions {
+        tolerances: Tolerances {
+            dual_feasibility: 0f64,
+            ..Tolerances::default()
+        },
+        ..SolverOptions::default()
+    };
+    let solution = optimize(&tableau.clone(), &options);
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(1, solution.iterations);
+    // The right side of the second row is a rounding error, no phase one needed
+    let tableau = vec![
+        vec![1.0, 1.0, 0.0, 0.0, 0.0],
+        vec![1.0, 1.0, 1.0, 0.0, 4.0],
+        vec![1.0, -1.0, 0.0, 1.0, -1e-12],
+    ];
+    let solution = optimize(&tableau, &SolverOptions::default());
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert!((solution.value - 4.0).abs() < 1e-9);
+    let x = solution.x.unwrap();
+    assert!((x[&0] - 2.0).abs() < 1e-9);
+    assert!((x[&1] - 2.0).abs() < 1e-9);
+}
+
+/// A dense tableau with pseudo random coefficients, `>=` rows (needing phase one) every fifth row.
+fn random_tableau(rows: usize, columns: usize) -> Vec<Vec<f64>> {
+    let mut state: u64 = 7;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        ((state >> 33) % 1000) as f64 / 100.0
+    };
+    let mut tableau = vec![(0..columns)
+        .map(|_| random() - 2.0)
+        .chain(vec![0f64; rows + 1])
+        .collect::<Vec<f64>>()];
+    for row_index in 0..rows {
+        let (factor, b) = if row_index % 5 == 4 {
+            (-1f64, 1.0 + random())
+        } else {
+            (1f64, 100.0 + 100.0 * random())
+        };
+        let mut row: Vec<f64> = (0..columns).map(|_| factor * random()).collect();
+        row.extend((0..rows).map(|column| if column == row_index { 1f64 } else { 0f64 }));
+        row.push(factor * b);
+        tableau.push(row);
+    }
+    tableau
+}
+
+#[test]
+fn revised() {
+    for &anti_cycling in &[AntiCycling::Bland, AntiCycling::Lexicographic] {
+        for &pricing in &[Pricing::Dantzig, Pricing::Devex, Pricing::Partial(2)] {
+            let options = SolverOptions {
+                anti_cycling,
+                pricing,
+                backend: Backend::Revised,
+                ..SolverOptions::default()
+            };
+            let tableaus = tableaus();
+            let solution = optimize(&tableaus[0].clone(), &options);
+            assert_eq!(SolveStatus::Optimal, solution.status);
+            assert!((solution.value - 7.6).abs() < 1e-9);
+            let x = solution.x.unwrap();
+            assert!((x[&0] - 3.6).abs() < 1e-9);
+            assert!((x[&1] - 0.4).abs() < 1e-9);
+            let solution = optimize(&tableaus[1].clone(), &options);
+            assert!((solution.value - 94.0).abs() < 1e-9);
+            let solution = optimize(&tableaus[2].clone(), &options);
+            assert_eq!(SolveStatus::Unbounded, solution.status);
+            let solution = optimize(&tableaus[3].clone(), &options);
+            assert!((solution.value - 70.0).abs() < 1e-9);
+            // The tableau is left untouched
+            assert_eq!(tableaus, self::tableaus());
+            let solution = optimize(&beale(), &options);
+            assert!((solution.value - 1.25).abs() < 1e-9);
+            let tableau = vec![
+                vec![1.0, 0.0, 0.0, 0.0],
+                vec![1.0, 1.0, 0.0, 1.0],
+                vec![-1.0, 0.0, 1.0, -2.0],
+            ];
+            let solution = optimize(&tableau, &options);
+            assert_eq!(SolveStatus::Infeasible, solution.status);
+        }
+    }
+}
+
+#[test]
+fn revised_refactorization() {
+    let options = SolverOptions {
+        backend: Backend::Revised,
+        ..SolverOptions::default()
+    };
+    let solution = optimize(&random_tableau(80, 120), &options);
+    let expected = optimize(&random_tableau(80, 120), &SolverOptions::default());
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(SolveStatus::Optimal, expected.status);
+    // More pivots than product-form updates between two factorizations
+    assert!(solution.iterations > lu::REFACTORIZATION_INTERVAL);
+    assert!((solution.value - expected.value).abs() < 1e-6);
+    let x = solution.x.unwrap();
+    for (variable, value) in expected.x.unwrap() {
+        assert!((x[&variable] - value).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn problem() {
+    for tableau in tableaus().iter() {
+        let problem = Problem::from_tableau(tableau);
+        assert_eq!(tableau, &problem.tableau());
+        // The revised backend reads the columns
+        let columns = problem.constraints.transpose(problem.variable_count());
+        for column in 0..problem.variable_count() {
+            let nonzeros = tableau[1..]
+                .iter()
+                .filter(|row| row[column] != 0f64)
+                .count();
+            assert_eq!(nonzeros, columns.row(column).count());
+            for (row_index, value) in columns.row(column) {
+                assert_eq!(tableau[row_index + 1][column], value);
+            }
+        }
+    }
+}
+
+#[test]
+fn bounds() {
+    // max 2x1 + x2, x1 + x2 <= 5, 0 <= x1 <= 3, x2 free
+    let mut problem = Problem::from_tableau(&[vec![2.0, 1.0, 0.0, 0.0], vec![1.0, 1.0, 1.0, 5.0]]);
+    problem.upper[0] = 3.0;
+    problem.lower[1] = -1.0 / 0.0;
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        };
+        let solution = super::solve(&problem, &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        // x1 moves to its upper bound without a pivot, then x2 enters the basis
+        assert_eq!(2, solution.iterations);
+        assert!((solution.value - 8.0).abs() < 1e-9);
+        let x = solution.x.unwrap();
+        assert_eq!(3.0, x[&0]);
+        assert!((x[&1] - 2.0).abs() < 1e-9);
+        assert_eq!(Option::Some(vec![1.0]), solution.duals);
+    }
+    // A fixed variable
+    problem.lower[0] = 3.0;
+    problem.upper[1] = 1.0;
+    let solution = super::solve(&problem, &SolverOptions::default());
+    assert!((solution.value - 7.0).abs() < 1e-9);
+    // Crossing bounds
+    problem.lower[0] = 4.0;
+    let solution = super::solve(&problem, &SolverOptions::default());
+    assert_eq!(SolveStatus::Infeasible, solution.status);
+}
+
+#[test]
+fn equality() {
+    // The first tableau with x1 + x2 = 1
+    // x* = (x1,x2) = (1, 0)
+    let mut problem = Problem::from_tableau(&tableaus()[0]);
+    problem.rhs[1] = 1.0;
+    problem.range[1] = 0.0;
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        };
+        let solution = super::solve(&problem, &options);
+        assert_eq!(SolveStatus::Optimal, solution.status);
+        assert!((solution.value - 2.0).abs() < 1e-9);
+        assert!((solution.x.unwrap()[&0] - 1.0).abs() < 1e-9);
+        // One dual value for the row
+        let duals = solution.duals.unwrap();
+        assert!(duals[0].abs() < 1e-9);
+        assert!((duals[1] - 2.0).abs() < 1e-9);
+    }
+    // Infeasible for non-negative variables
+    problem.rhs[1] = -1.0;
+    let solution = super::solve(&problem, &SolverOptions::default());
+    assert_eq!(SolveStatus::Infeasible, solution.status);
+}
+
+#[test]
+fn tableau_factorization() {
+    // The basis (x2, x1) of the first tableau's optimum
+    let problem = Problem::from_tableau(&tableaus()[0]);
+    let columns: Vec<lu::SparseColumn> = vec![
+        vec![(0, 2.0), (1, 1.0)],
+        vec![(0, -3.0), (1, 1.0)],
+        vec![(0, 1.0)],
+        vec![(1, 1.0)],
+    ];
+    let kernel = <Tableau as Kernel>::factorize(&columns, &[1, 0], 2, 1e-10).unwrap();
+    let x = kernel.solve(&problem.rhs);
+    assert!((x[0] - 0.4).abs() < 1e-12);
+    assert!((x[1] - 3.6).abs() < 1e-12);
+    assert_eq!(vec![1.0, 0.0], kernel.column(&columns, 1));
+    assert!(<Tableau as Kernel>::factorize(&columns, &[0, 0], 2, 1e-10).is_none());
+}
+
+#[test]
+fn warm_start() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        };
+        for tableau in [&tableaus()[0], &tableaus()[3]] {
+            let mut problem = Problem::from_tableau(tableau);
+            let solution = super::solve(&problem, &options);
+            problem.basis = solution.basis.clone();
+            let warm = super::solve(&problem, &options);
+            assert_eq!(0, warm.iterations);
+            assert!((warm.value - solution.value).abs() < 1e-9);
+            assert_eq!(solution.basis, warm.basis);
+        }
+        // The dual simplex algorithm proves infeasibility
+        let mut problem = Problem::from_tableau(&tableaus()[0]);
+        problem.basis = super::solve(&problem, &options).basis;
+        problem.rhs[1] = -1.0;
+        let solution = super::solve(&problem, &options);
+        assert_eq!(SolveStatus::Infeasible, solution.status);
+    }
+}
+
+#[test]
+fn dual_simplex() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        for &anti_cycling in &[AntiCycling::Bland, AntiCycling::Lexicographic] {
+            let options = SolverOptions {
+                backend,
+                anti_cycling,
+                ..SolverOptions::default()
+            };
+            // The optimal basis (x1, x2) of the first tableau gets x2 = -0.8
+            let mut problem = Problem::from_tableau(&tableaus()[0]);
+            problem.basis = super::solve(&problem, &options).basis;
+            problem.rhs[1] = 1.0;
+            let solution = super::solve(&problem, &options);
+            assert_eq!(SolveStatus::Optimal, solution.status);
+            assert_eq!(1, solution.iterations);
+            assert!((solution.value - 2.0).abs() < 1e-9);
+            let x = solution.x.unwrap();
+            assert!((x[&0] - 1.0).abs() < 1e-9);
+            assert!(x[&1].abs() < 1e-9);
+            // A tightened bound, as in branch-and-bound
+            let mut problem = Problem::from_tableau(&tableaus()[1]);
+            problem.basis = super::solve(&problem, &options).basis;
+            problem.upper[0] = 10.0;
+            let solution = super::solve(&problem, &options);
+            assert_eq!(SolveStatus::Optimal, solution.status);
+            assert!((solution.value - 54.0).abs() < 1e-9);
+        }
+    }
+}
+
+/// Checks a Farkas certificate: `y b < min y A x` within the bounds, `y >= 0` on inequalities.
+fn assert_farkas(problem: &Problem, y: &[f64]) {
+    let mut r = vec![0f64; problem.variable_count()];
+    for (row, &y) in y.iter().enumerate() {
+        assert!(problem.range[row] == 0f64 || y >= -1e-9);
+        for (column, value) in problem.constraints.row(row) {
+            r[column] += y * value;
+        }
+    }
+    let minimum: f64 = r
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| r.abs() > 1e-9)
+        .map(|(column, &r)| f64::min(r * problem.lower[column], r * problem.upper[column]))
+        .sum();
+    let yb: f64 = y.iter().zip(problem.rhs.iter()).map(|(y, b)| y * b).sum();
+    assert!(
+        yb < minimum - 1e-9
+            || problem
+                .lower
+                .iter()
+                .zip(problem.upper.iter())
+                .any(|(l, u)| l > u)
+    );
+}
+
+/// Checks an unbounded ray: `A r <= 0` (zero on equalities), `c r > 0`, moving towards infinite bounds only.
+fn assert_ray(problem: &Problem, r: &[f64]) {
+    for row in 0..problem.rhs.len() {
+        let ar: f64 = problem
+            .constraints
+            .row(row)
+            .map(|(column, value)| value * r[column])
+            .sum();
+        assert!(ar <= 1e-9 && (problem.range[row] > 0f64 || ar >= -1e-9));
+    }
+    let cr: f64 = problem
+        .objective
+        .iter()
+        .zip(r.iter())
+        .map(|(c, r)| c * r)
+        .sum();
+    assert!(cr > 1e-9);
+    for (column, &r) in r.iter().enumerate() {
+        assert!(r <= 1e-9 || problem.upper[column].is_infinite());
+        assert!(r >= -1e-9 || problem.lower[column].is_infinite());
+    }
+}
+
+#[test]
+fn certificates() {
+    // x1 <= 1, x1 >= 2
+    let infeasible = Problem::from_tableau(&[
+        vec![1.0, 0.0, 0.0, 0.0],
+        vec![1.0, 1.0, 0.0, 1.0],
+        vec![-1.0, 0.0, 1.0, -2.0],
+    ]);
+    // x1 + x2 = -1
+    let mut equality = Problem::from_tableau(&tableaus()[0]);
+    equality.rhs[1] = -1.0;
+    equality.range[1] = 0.0;
+    let mut crossing = Problem::from_tableau(&tableaus()[0]);
+    crossing.lower[0] = 2.0;
+    crossing.upper[0] = 1.0;
+    // max -x1 + x2, x1 + x2 <= 5, x1 free
+    let mut free = Problem::from_tableau(&[vec![-1.0, 1.0, 0.0, 0.0], vec![1.0, 1.0, 1.0, 5.0]]);
+    free.lower[0] = -1.0 / 0.0;
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        for &initialization in &[Initialization::TwoPhases, Initialization::BigM(1e6)] {
+            let options = SolverOptions {
+                backend,
+                initialization,
+                ..SolverOptions::default()
+            };
+            for problem in [&infeasible, &equality, &crossing] {
+                let solution = super::solve(problem, &options);
+                assert_eq!(SolveStatus::Infeasible, solution.status);
+                assert_farkas(problem, &solution.farkas.unwrap());
+            }
+            // From the dual simplex algorithm
+            let mut warm = Problem::from_tableau(&tableaus()[0]);
+            warm.basis = super::solve(&warm, &options).basis;
+            warm.rhs[1] = -1.0;
+            let solution = super::solve(&warm, &options);
+            assert_eq!(SolveStatus::Infeasible, solution.status);
+            assert_farkas(&warm, &solution.farkas.unwrap());
+            for problem in [&Problem::from_tableau(&tableaus()[2]), &free] {
+                let solution = super::solve(problem, &options);
+                assert_eq!(SolveStatus::Unbounded, solution.status);
+                assert!(solution.farkas.is_none());
+                assert_ray(problem, &solution.ray.unwrap());
+            }
+        }
+    }
+    // M too small for the penalty to remove the artificial variable, phase one decides
+    let options = SolverOptions {
+        initialization: Initialization::BigM(1.0),
+        ..SolverOptions::default()
+    };
+    let mut problem = Problem::from_tableau(&[vec![5.0, 0.0, 0.0], vec![-1.0, 1.0, -1.0]]);
+    problem.upper[0] = 2.0;
+    let solution = super::solve(&problem, &options);
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert!((solution.value - 10.0).abs() < 1e-9);
+}
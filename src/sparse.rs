@@ -0,0 +1,118 @@
+//! Sparse matrix storage for the constraints.
+
+/// A sparse matrix in compressed sparse row (CSR) format.
+///
+/// Each row holds its nonzero entries ordered by column index.
+#[derive(PartialEq, Clone, Debug)]
+pub struct SparseMatrix {
+    /// Position of each row's first entry in `columns` and `values`, followed by the number of entries
+    starts: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl Default for SparseMatrix {
+    fn default() -> Self {
+        SparseMatrix {
+            starts: vec![0],
+            columns: vec![],
+            values: vec![],
+        }
+    }
+}
+
+impl SparseMatrix {
+    /// Creates an empty matrix.
+    pub fn new() -> Self {
+        SparseMatrix::default()
+    }
+
+    /// Appends a row given as `(column, value)` pairs. Entries of the same column are summed up, zeros are dropped.
+    pub fn push_row(&mut self, mut entries: Vec<(usize, f64)>) {
+        entries.sort_by_key(|&(column, _)| column);
+        let mut merged: Vec<(usize, f64)> = Vec::with_capacity(entries.len());
+        for (column, value) in entries {
+            match merged.last_mut() {
+                Option::Some(last) if last.0 == column => last.1 += value,
+                _ => merged.push((column, value)),
+            }
+        }
+        for (column, value) in merged {
+            if value != 0f64 {
+                self.columns.push(column);
+                self.values.push(value);
+            }
+        }
+        self.starts.push(self.columns.len());
+    }
+
+    /// Removes a row, the following rows moving up.
+    pub fn remove_row(&mut self, row: usize) {
+        let (start, end) = (self.starts[row], self.starts[row + 1]);
+        self.columns.drain(start..end);
+        self.values.drain(start..end);
+        self.starts.remove(row + 1);
+        for position in self.starts[row + 1..].iter_mut() {
+            *position -= end - start;
+        }
+    }
+
+    /// Removes a column's entries, the following columns' indices decreasing by one.
+    pub fn remove_column(&mut self, column: usize) {
+        let mut matrix = SparseMatrix::new();
+        for row in 0..self.row_count() {
+            matrix.push_row(
+                self.row(row)
+                    .filter(|&(index, _)| index != column)
+                    .map(|(index, value)| (if index > column { index - 1 } else { index }, value))
+                    .collect(),
+            );
+        }
+        *self = matrix;
+    }
+
+    /// Returns the entries of a row as `(column, value)` pairs.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.starts[row]..self.starts[row + 1];
+        self.columns[range.clone()]
+            .iter()
+            .cloned()
+            .zip(self.values[range].iter().cloned())
+    }
+
+    /// Returns the number of rows.
+    pub fn row_count(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    /// Returns the number of stored (nonzero) entries.
+    pub fn nonzero_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the transposed matrix, i.e. the columns (up to `column_count`) in compressed form (CSC).
+    pub fn transpose(&self, column_count: usize) -> SparseMatrix {
+        let mut counts = vec![0usize; column_count + 1];
+        for &column in self.columns.iter() {
+            counts[column + 1] += 1;
+        }
+        for column in 0..column_count {
+            counts[column + 1] += counts[column];
+        }
+        let starts = counts.clone();
+        let mut columns = vec![0usize; self.nonzero_count()];
+        let mut values = vec![0f64; self.nonzero_count()];
+        for row in 0..self.row_count() {
+            for (column, value) in self.row(row) {
+                columns[counts[column]] = row;
+                values[counts[column]] = value;
+                counts[column] += 1;
+            }
+        }
+        SparseMatrix {
+            starts,
+            columns,
+            values,
+        }
+    }
+}
@@ -1,29 +1,24 @@
-use crate::*;
+#![allow(clippy::vec_init_then_push)]
 
-#[test]
-fn update() {
-    let mut model = Model::new("Test-model", Objective::Max);
-    assert!(State::VariableRegistration == model.state);
-    model.update();
-    assert!(State::ConstraintRegistration == model.state);
-    model.update();
-    assert!(State::PostRegistration == model.state);
-    model.update();
-    assert!(State::PostRegistration == model.state);
-}
+use crate::*;
 
 #[test]
-fn update_indirect() {
+fn registration_order() {
     let mut model = Model::new("Test-model", Objective::Max);
     let mut vars: Vec<Var> = vec![];
     vars.push(model.reg_var(3.0));
-    assert!(State::VariableRegistration == model.state);
-    vars.push(model.reg_var(3.0));
+    model.update();
     model.reg_constr(vec![Summand(8.4, &vars[0])], Operator::Le, 6.0);
-    assert!(State::ConstraintRegistration == model.state);
-    model.reg_constr(vec![Summand(8.4, &vars[0])], Operator::Le, 5.0);
+    // Variables can be registered after constraints
+    vars.push(model.reg_var(3.0));
+    model.reg_constr(vec![Summand(8.4, &vars[1])], Operator::Le, 5.0);
     model.optimize();
-    assert!(State::PostRegistration == model.state);
+    assert!(model.status.is_some());
+    // Changes discard the solution
+    vars.push(model.reg_var(1.0));
+    assert!(model.status.is_none());
+    assert!(model.variables[0].x.is_none());
+    assert_eq!(3, model.basis.as_ref().unwrap().len() - model.rhs.len());
 }
 
 #[test]
@@ -36,32 +31,109 @@ fn reg_var_and_x() {
     vars.push(model.reg_var(3.0));
     variables.push(Variable {
         name: Option::None,
-        uuid: vars.last().unwrap().reference,
+        index: vars.last().unwrap().index,
         x: Option::None,
         objective_value: 3.0,
+        var_type: VarType::Continuous,
+        lower: 0f64,
+        upper: 1.0 / 0.0,
+        reduced_cost: Option::None,
+        cost_range: Option::None,
+        ray: Option::None,
     });
-    assert!(vars[0].reference == model.variables[0].uuid);
+    assert!(vars[0].index == model.variables[0].index);
     assert!(variables == model.variables);
     // Add variable
     vars.push(model.reg_var(4.999));
     variables.push(Variable {
         name: Option::None,
-        uuid: vars.last().unwrap().reference,
+        index: vars.last().unwrap().index,
         x: Option::None,
         objective_value: 4.999,
+        var_type: VarType::Continuous,
+        lower: 0f64,
+        upper: 1.0 / 0.0,
+        reduced_cost: Option::None,
+        cost_range: Option::None,
+        ray: Option::None,
     });
-    assert!(vars[1].reference == model.variables[1].uuid);
-    assert!(vars[1].reference != model.variables[0].uuid);
+    assert!(vars[1].index == model.variables[1].index);
+    assert!(vars[1].index != model.variables[0].index);
     assert!(variables == model.variables);
 }
 
+#[test]
+fn set_var_type() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let var = model.reg_var(3.0);
+    model.set_var_type(&var, VarType::Integer);
+    assert!(VarType::Integer == model.variables[0].var_type);
+    model.set_var_type(&var, VarType::Binary);
+    assert!(VarType::Binary == model.variables[0].var_type);
+}
+
+#[test]
+fn remove() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let x = model.reg_var(1.0);
+    let y = model.reg_var(2.0);
+    let z = model.reg_var(3.0);
+    let c_0 = model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &z)], Operator::E, 4.0);
+    let c_1 = model.reg_constr(vec![Summand(1.0, &y), Summand(2.0, &z)], Operator::Ge, 1.0);
+    model.remove_var(&y);
+    assert_eq!(2, model.variables.len());
+    assert_eq!(
+        vec![(1, -2.0)],
+        model.matrix.row(1).collect::<Vec<(usize, f64)>>()
+    );
+    model.remove_constr(&c_0);
+    assert_eq!(1, model.matrix.row_count());
+    assert_eq!(
+        vec![(1, -2.0)],
+        model.matrix.row(0).collect::<Vec<(usize, f64)>>()
+    );
+    assert_eq!(vec![-1.0], model.rhs);
+    assert_eq!(
+        (0, -1f64),
+        (model.constraints[0].row, model.constraints[0].factor)
+    );
+    model.set_rhs(&c_1, 3.0);
+    assert_eq!(vec![-3.0], model.rhs);
+}
+
 #[test]
 #[should_panic]
-fn panic_reg_var() {
+fn remove_var() {
     let mut model = Model::new("Test-model", Objective::Max);
-    model.reg_var(3.999);
-    model.update();
-    model.reg_var(4.989);
+    let x = model.reg_var(1.0);
+    model.remove_var(&x);
+    model.set_objective_value(&x, 2.0);
+}
+
+#[test]
+fn handles() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let vars: Vec<Var> = (0..3).map(|index| model.reg_var(index as f64)).collect();
+    let c_0 = model.reg_constr(vec![Summand(1.0, &vars[0])], Operator::Le, 1.0);
+    let c_1 = model.reg_constr(vec![Summand(1.0, &vars[2])], Operator::Le, 2.0);
+    // Handles keep their registration index, the positions follow removals
+    model.remove_var(&vars[1]);
+    model.remove_constr(&c_0);
+    assert_eq!(
+        vec![Option::Some(0), Option::None, Option::Some(1)],
+        model.variable_positions
+    );
+    assert_eq!(
+        vec![Option::None, Option::Some(0)],
+        model.constraint_positions
+    );
+    model.set_objective_value(&vars[2], 5.0);
+    assert_eq!(5.0, model.variables[1].objective_value);
+    model.set_rhs(&c_1, 3.0);
+    assert_eq!(vec![3.0], model.rhs);
+    let y = model.reg_var(1.0);
+    assert_eq!(3, y.index);
+    assert_eq!(Option::Some(2), model.variable_positions[3]);
 }
 
 #[test]
@@ -103,5 +175,224 @@ fn reg_constr() {
         Operator::Le,
         6.0,
     );
-    model.init_tableau();
+    model.reg_constr(vec![Summand(2.0, &vars[0])], Operator::Ge, 1.0);
+    // Only nonzero coefficients are stored, summands of the same variable are summed up
+    assert_eq!(4, model.matrix.nonzero_count());
+    assert_eq!(
+        vec![(0, 8.4), (1, 3.9)],
+        model.matrix.row(0).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![(1, 12.3)], model.matrix.row(1).collect::<Vec<_>>());
+    assert_eq!(vec![(0, -2.0)], model.matrix.row(2).collect::<Vec<_>>());
+    assert_eq!(vec![6.0, 6.0, -1.0], model.rhs);
+}
+
+#[test]
+#[should_panic]
+fn dual() {
+    let mut model_0 = Model::new("Test-model 0", Objective::Max);
+    let mut model_1 = Model::new("Test-model 1", Objective::Max);
+    let var_0 = model_0.reg_var(3.0);
+    let var_1 = model_1.reg_var(4.0);
+    let constr = model_1.reg_constr(vec![Summand(1.0, &var_1)], Operator::Le, 1.0);
+    model_0.reg_constr(vec![Summand(1.0, &var_0)], Operator::Le, 1.0);
+    // Test unsolved
+    assert!(model_1.dual(&constr).is_err());
+    model_0.dual(&constr).unwrap();
+}
+
+#[test]
+fn reg_var_bounded() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let x = model.reg_var_bounded(2.0, -1.0, 4.5);
+    let y = model.reg_var(1.0);
+    assert!(x.index == model.variables[0].index);
+    assert_eq!(
+        (-1.0, 4.5),
+        (model.variables[0].lower, model.variables[0].upper)
+    );
+    assert!(y.index == model.variables[1].index);
+    assert_eq!(
+        (0.0, 1.0 / 0.0),
+        (model.variables[1].lower, model.variables[1].upper)
+    );
+}
+
+#[test]
+fn from_mps() {
+    let mps = "NAME example
+OBJSENSE MAX
+ROWS
+ N obj
+ L c1
+ G c2
+ E c3
+ E c4
+COLUMNS
+ MARKER 'MARKER' 'INTORG'
+ x obj 1 c1 2
+ MARKER 'MARKER' 'INTEND'
+ y c2 1 c3 1
+ z c4 1
+RHS
+ RHS c1 4 c3 2
+RANGES
+ c1 3
+ c3 -1
+ c4 2
+BOUNDS
+ UP BND x -2
+ FR BND y
+ BV z
+ENDATA
+";
+    let model = Model::from_mps(mps, MpsFormat::Free).unwrap();
+    assert_eq!("example", model.name);
+    assert!(matches!(model.objective, Objective::Max));
+    let names: Vec<&str> = model
+        .variables
+        .iter()
+        .map(|variable| variable.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(vec!["x", "y", "z"], names);
+    let bounds: Vec<(f64, f64, VarType)> = model
+        .variables
+        .iter()
+        .map(|variable| (variable.lower, variable.upper, variable.var_type))
+        .collect();
+    // A negative upper bound frees the lower bound
+    assert_eq!(
+        vec![
+            (-1.0 / 0.0, -2.0, VarType::Integer),
+            (-1.0 / 0.0, 1.0 / 0.0, VarType::Continuous),
+            (0.0, 1.0 / 0.0, VarType::Binary),
+        ],
+        bounds
+    );
+    // The ranges of an equality give its direction
+    let constraints: Vec<(&str, f64, f64, f64)> = model
+        .constraints
+        .iter()
+        .map(|constraint| {
+            (
+                constraint.name.as_deref().unwrap(),
+                constraint.factor,
+                constraint.factor * model.rhs[constraint.row],
+                constraint.range,
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("c1", 1.0, 4.0, 3.0),
+            ("c2", -1.0, 0.0, 1.0 / 0.0),
+            ("c3", 1.0, 2.0, 1.0),
+            ("c4", -1.0, 0.0, 2.0),
+        ],
+        constraints
+    );
+}
+
+#[test]
+fn from_lp() {
+    let lp = "\\Problem name: example
+Maximize
+ obj: x + 2.5 y - z \\ a comment
+   + 0 w
+Subject To
+ c1: 2 x + 3y - x <= 4
+ -y >= -2
+ c3: 1 <= x + z <= 3
+ c4: 5 >= z + w >= 2
+ c5: x = 1.5e0
+Bounds
+ x >= -3
+ -inf <= y <= 10
+ z free
+ w = 1
+ v <= 4
+Generals
+ y
+Binary
+ v
+End
+";
+    let model = Model::from_lp(lp).unwrap();
+    assert_eq!("example", model.name);
+    assert_eq!(Objective::Max, model.objective);
+    let variables: Vec<(&str, f64, f64, f64, VarType)> = model
+        .variables
+        .iter()
+        .map(|variable| {
+            (
+                variable.name.as_deref().unwrap(),
+                variable.objective_value,
+                variable.lower,
+                variable.upper,
+                variable.var_type,
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("x", 1.0, -3.0, 1.0 / 0.0, VarType::Continuous),
+            ("y", 2.5, -1.0 / 0.0, 10.0, VarType::Integer),
+            ("z", -1.0, -1.0 / 0.0, 1.0 / 0.0, VarType::Continuous),
+            ("w", 0.0, 1.0, 1.0, VarType::Continuous),
+            // Binary variables keep the default bounds
+            ("v", 0.0, 0.0, 1.0 / 0.0, VarType::Binary),
+        ],
+        variables
+    );
+    // Ranged constraints become `<=` constraints with their range below the right side
+    let constraints: Vec<_> = model
+        .constraints
+        .iter()
+        .map(|constraint| {
+            (
+                constraint.name.as_deref(),
+                model.matrix.row(constraint.row).collect(),
+                constraint.factor,
+                constraint.factor * model.rhs[constraint.row],
+                constraint.range,
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            (
+                Option::Some("c1"),
+                vec![(0, 1.0), (1, 3.0)],
+                1.0,
+                4.0,
+                1.0 / 0.0
+            ),
+            (Option::None, vec![(1, 1.0)], -1.0, -2.0, 1.0 / 0.0),
+            (Option::Some("c3"), vec![(0, 1.0), (2, 1.0)], 1.0, 3.0, 2.0),
+            (Option::Some("c4"), vec![(2, 1.0), (3, 1.0)], 1.0, 5.0, 3.0),
+            (Option::Some("c5"), vec![(0, 1.0)], 1.0, 1.5, 0.0),
+        ],
+        constraints
+    );
+}
+
+#[test]
+fn lin_expr() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let x = model.reg_var(1.0);
+    let y = model.reg_var(1.0);
+    let expr = 2.0 * x - 3.0 * (y + 1.0) + x * 0.5 - (4.0 - y);
+    assert_eq!(&[(2.0, x), (-3.0, y), (0.5, x), (1.0, y)], expr.terms());
+    assert_eq!(-7.0, expr.constant());
+    let mut sum: LinExpr = [x, y].iter().map(|&var| 2.0 * var).sum();
+    sum -= Summand(1.0, &x);
+    assert_eq!(&[(2.0, x), (2.0, y), (-1.0, x)], sum.terms());
+    // Coefficients of the same variable are summed up, the constant moves to the right side
+    model.add_constr(expr, Operator::Le, 3.0);
+    let constraint = &model.constraints[0];
+    assert_eq!(
+        vec![(0, 2.5), (1, -2.0)],
+        model.matrix.row(constraint.row).collect::<Vec<_>>()
+    );
+    assert_eq!(10.0, model.rhs[constraint.row]);
 }
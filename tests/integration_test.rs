@@ -1,3 +1,5 @@
+#![allow(clippy::vec_init_then_push)]
+
 use linprog::*;
 use std::collections::HashMap;
 
@@ -76,6 +78,7 @@ fn solve_3() {
     );
     model.optimize();
     // Test
+    assert_eq!(SolveStatus::Unbounded, model.status().unwrap());
     assert!(model.x(&vars[0]).is_err());
     assert!(model.x(&vars[1]).is_err());
     assert_eq!(1.0 / 0.0, model.optimum().unwrap());
@@ -209,7 +212,7 @@ fn readme_example_story() {
 
     for (&machine, &max_time) in &machines {
         let mut sum: Vec<Summand> = Vec::new();
-        for (&product, _) in &products {
+        for &product in products.keys() {
             sum.push(Summand(time_needed[&(product, machine)], &vars[product]));
         }
         model.reg_constr(sum, Operator::Le, max_time);
@@ -230,3 +233,1494 @@ fn readme_example_story() {
     assert_eq!(47.61904761904763, model.x(&vars["Product C"]).unwrap());
     assert_eq!(22738.095238095237, model.optimum().unwrap());
 }
+
+#[test]
+fn solve_integer() {
+    let mut model = Model::new("Test-model (branch-and-bound)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    // x* = (x1,x2) = (2, 4), LP relaxation: (2.25, 3.75)
+    // opt: 26
+    vars.push(model.reg_var(5.0));
+    vars.push(model.reg_var(4.0));
+    model.set_var_type(&vars[0], VarType::Integer);
+    model.set_var_type(&vars[1], VarType::Integer);
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Le,
+        6.0,
+    );
+    model.reg_constr(
+        vec![Summand(10.0, &vars[0]), Summand(6.0, &vars[1])],
+        Operator::Le,
+        45.0,
+    );
+    model.optimize();
+    // Test
+    assert_eq!(2.0, model.x(&vars[0]).unwrap());
+    assert_eq!(4.0, model.x(&vars[1]).unwrap());
+    assert_eq!(26.0, model.optimum().unwrap());
+    assert!(model.gap().unwrap() <= 1e-4);
+    assert!(model.node_count() > 1);
+}
+
+#[test]
+fn solve_binary() {
+    let mut model = Model::new("Test-model (knapsack)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    // x* = (0, 1, 1, 1)
+    // opt: 25
+    for &value in &[8.0, 11.0, 6.0, 8.0] {
+        let var = model.reg_var(value);
+        model.set_var_type(&var, VarType::Binary);
+        vars.push(var);
+    }
+    model.reg_constr(
+        vec![
+            Summand(5.1, &vars[0]),
+            Summand(7.3, &vars[1]),
+            Summand(4.2, &vars[2]),
+            Summand(3.4, &vars[3]),
+        ],
+        Operator::Le,
+        15.0,
+    );
+    model.optimize();
+    // Test
+    assert_eq!(0.0, model.x(&vars[0]).unwrap());
+    assert_eq!(1.0, model.x(&vars[1]).unwrap());
+    assert_eq!(1.0, model.x(&vars[2]).unwrap());
+    assert_eq!(1.0, model.x(&vars[3]).unwrap());
+    assert_eq!(25.0, model.optimum().unwrap());
+}
+
+#[test]
+fn solve_integer_min() {
+    let mut model = Model::new("Test-model (branch-and-bound)", Objective::Min);
+    let mut vars: Vec<Var> = vec![];
+    // x* = (x1,x2) = (2, 0.75), LP relaxation: (1.1, 1.2)
+    // opt: 6.25
+    vars.push(model.reg_var(2.0));
+    vars.push(model.reg_var(3.0));
+    model.set_var_type(&vars[0], VarType::Integer);
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(2.0, &vars[1])],
+        Operator::Ge,
+        3.5,
+    );
+    model.reg_constr(
+        vec![Summand(3.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Ge,
+        4.5,
+    );
+    model.optimize();
+    // Test
+    assert_eq!(2.0, model.x(&vars[0]).unwrap());
+    assert_eq!(0.75, model.x(&vars[1]).unwrap());
+    assert_eq!(6.25, model.optimum().unwrap());
+}
+
+#[test]
+fn node_limit() {
+    let mut model = Model::new("Test-model (node limit)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    vars.push(model.reg_var(5.0));
+    vars.push(model.reg_var(4.0));
+    model.set_var_type(&vars[0], VarType::Integer);
+    model.set_var_type(&vars[1], VarType::Integer);
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Le,
+        6.0,
+    );
+    model.reg_constr(
+        vec![Summand(10.0, &vars[0]), Summand(6.0, &vars[1])],
+        Operator::Le,
+        45.0,
+    );
+    model.set_node_limit(1);
+    model.optimize();
+    // Test: only the root relaxation was solved, no incumbent is known
+    assert_eq!(SolveStatus::NodeLimit, model.status().unwrap());
+    assert_eq!(1, model.node_count());
+    assert_eq!(-1.0 / 0.0, model.optimum().unwrap());
+    assert!(model.x(&vars[0]).is_err());
+    assert_eq!(26.25, model.best_bound().unwrap());
+    // Raising the limit discards the stopped search
+    model.set_node_limit(1000);
+    model.optimize();
+    assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+    assert_eq!(26.0, model.optimum().unwrap());
+}
+
+#[test]
+fn gap_tolerance() {
+    // x* = (x1,x2,x3,x4) = (0, 1, 0, 1)
+    // opt: 31
+    let mut model = Model::new("Test-model (gap tolerance)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    for &value in &[18.0, 11.0, 23.0, 20.0] {
+        let var = model.reg_var(value);
+        model.set_var_type(&var, VarType::Binary);
+        vars.push(var);
+    }
+    model.add_constr(
+        12.0 * vars[0] + 5.0 * vars[1] + 16.0 * vars[2] + 14.0 * vars[3],
+        Operator::Le,
+        20.0,
+    );
+    model.set_gap_tolerance(0.3);
+    model.optimize();
+    // Test: the incumbent may be worse, the bound of the pruned nodes is kept
+    assert!(model.optimum().unwrap() < 31.0);
+    assert!(model.best_bound().unwrap() >= 31.0);
+    assert!(model.gap().unwrap() > 0.0);
+    model.set_gap_tolerance(0.0);
+    model.optimize();
+    assert_eq!(31.0, model.optimum().unwrap());
+}
+
+#[test]
+fn solve_infeasible() {
+    let mut model = Model::new("Test-model (infeasible)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    vars.push(model.reg_var(1.0));
+    vars.push(model.reg_var(1.0));
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Le,
+        2.0,
+    );
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Ge,
+        3.0,
+    );
+    // Test
+    assert_eq!(SolveStatus::Infeasible, model.solve());
+    assert!(model.x(&vars[0]).is_err());
+    assert_eq!(-1.0 / 0.0, model.optimum().unwrap());
+}
+
+#[test]
+fn solve_integer_infeasible() {
+    let mut model = Model::new("Test-model (integer infeasible)", Objective::Min);
+    let var = model.reg_var(1.0);
+    model.set_var_type(&var, VarType::Integer);
+    model.reg_constr(vec![Summand(2.0, &var)], Operator::Ge, 1.0);
+    model.reg_constr(vec![Summand(2.0, &var)], Operator::Le, 1.5);
+    // Test
+    assert_eq!(SolveStatus::Infeasible, model.solve());
+    assert!(model.x(&var).is_err());
+    assert_eq!(1.0 / 0.0, model.optimum().unwrap());
+}
+
+#[test]
+fn solve_integer_unbounded() {
+    // The relaxation is unbounded, but y can not be integral
+    let mut model = Model::new(
+        "Test-model (integer infeasible, unbounded relaxation)",
+        Objective::Max,
+    );
+    let x = model.reg_var(1.0);
+    let y = model.reg_var(0.0);
+    model.set_var_type(&y, VarType::Integer);
+    model.add_constr(y, Operator::E, 0.5);
+    // Test
+    assert_eq!(SolveStatus::Infeasible, model.solve());
+    assert!(model.x(&x).is_err());
+    assert!(model.unbounded_ray().is_err());
+    assert_eq!(-1.0 / 0.0, model.optimum().unwrap());
+    // With an integer solution, the model is unbounded
+    model.set_rhs(&model.constrs()[0], 1.0);
+    assert_eq!(SolveStatus::Unbounded, model.solve());
+    assert!(model.x(&x).is_err());
+    assert_eq!(1.0 / 0.0, model.optimum().unwrap());
+    assert!(model.unbounded_ray().unwrap()[0].1 > 0.0);
+}
+
+#[test]
+fn zero_optimum() {
+    // Neither the negative zero of an empty sum nor a negated zero is reported
+    let mut model = Model::new("Test-model (empty)", Objective::Max);
+    model.optimize();
+    assert!(model.optimum().unwrap().is_sign_positive());
+    assert!(!model.to_string().contains("-0"));
+    let mut model = Model::new("Test-model (zero, min)", Objective::Min);
+    let x = model.reg_var(0.0);
+    let c = model.reg_constr(vec![Summand(1.0, &x)], Operator::Le, 1.0);
+    model.optimize();
+    assert!(model.optimum().unwrap().is_sign_positive());
+    assert!(model.reduced_cost(&x).unwrap().is_sign_positive());
+    assert!(model.dual(&c).unwrap().is_sign_positive());
+    model.set_var_type(&x, VarType::Integer);
+    model.optimize();
+    assert!(model.optimum().unwrap().is_sign_positive());
+    assert!(model.best_bound().unwrap().is_sign_positive());
+}
+
+#[test]
+fn iteration_limit() {
+    let mut model = Model::new("Test-model (iteration limit)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    vars.push(model.reg_var(3.0));
+    vars.push(model.reg_var(2.0));
+    model.reg_constr(
+        vec![Summand(-1.0, &vars[0]), Summand(2.0, &vars[1])],
+        Operator::Le,
+        14.0,
+    );
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(-1.0, &vars[1])],
+        Operator::Le,
+        3.0,
+    );
+    model.set_options(SolverOptions {
+        iteration_limit: Option::Some(1),
+        ..SolverOptions::default()
+    });
+    // Test
+    assert_eq!(SolveStatus::IterationLimit, model.solve());
+    assert_eq!(1, model.iteration_count());
+    assert!(model.x(&vars[0]).is_err());
+    assert!(model.optimum().is_err());
+}
+
+#[test]
+fn solve_degenerate() {
+    for &anti_cycling in &[AntiCycling::Bland, AntiCycling::Lexicographic] {
+        let mut model = Model::new("Test-model (Beale)", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        // x* = (x1,x2,x3,x4) = (1, 0, 1, 0)
+        // opt: 1.25
+        vars.push(model.reg_var(0.75));
+        vars.push(model.reg_var(-20.0));
+        vars.push(model.reg_var(0.5));
+        vars.push(model.reg_var(-6.0));
+        model.reg_constr(
+            vec![
+                Summand(0.25, &vars[0]),
+                Summand(-8.0, &vars[1]),
+                Summand(-1.0, &vars[2]),
+                Summand(9.0, &vars[3]),
+            ],
+            Operator::Le,
+            0.0,
+        );
+        model.reg_constr(
+            vec![
+                Summand(0.5, &vars[0]),
+                Summand(-12.0, &vars[1]),
+                Summand(-0.5, &vars[2]),
+                Summand(3.0, &vars[3]),
+            ],
+            Operator::Le,
+            0.0,
+        );
+        model.reg_constr(vec![Summand(1.0, &vars[2])], Operator::Le, 1.0);
+        model.set_options(SolverOptions {
+            iteration_limit: Option::Some(100),
+            anti_cycling,
+            ..SolverOptions::default()
+        });
+        // Test
+        assert_eq!(SolveStatus::Optimal, model.solve());
+        assert!((model.optimum().unwrap() - 1.25).abs() < 1e-9);
+        assert!((model.x(&vars[0]).unwrap() - 1.0).abs() < 1e-9);
+        assert!((model.x(&vars[2]).unwrap() - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn pricing() {
+    for &pricing in &[
+        Pricing::Dantzig,
+        Pricing::SteepestEdge,
+        Pricing::Devex,
+        Pricing::Partial(1),
+    ] {
+        let mut model = Model::new("Readme example", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        // x* = (x1,x2) = (130, 20)
+        // opt: 490
+        vars.push(model.reg_var(3.0));
+        vars.push(model.reg_var(5.0));
+        model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(2.0, &vars[1])],
+            Operator::Le,
+            170.0,
+        );
+        model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::Le,
+            150.0,
+        );
+        model.reg_constr(
+            vec![Summand(0.0, &vars[0]), Summand(3.0, &vars[1])],
+            Operator::Le,
+            180.0,
+        );
+        model.optimize_with(SolverOptions {
+            pricing,
+            ..SolverOptions::default()
+        });
+        // Test
+        assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+        assert!((model.x(&vars[0]).unwrap() - 130.0).abs() < 1e-9);
+        assert!((model.x(&vars[1]).unwrap() - 20.0).abs() < 1e-9);
+        assert!((model.optimum().unwrap() - 490.0).abs() < 1e-9);
+        assert!(model.iteration_count() > 0);
+    }
+}
+
+#[test]
+fn solve_rounding_errors() {
+    let mut model = Model::new("Test-model (rounding errors)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    // x* = (x1,x2) = (2, 1)
+    // opt: 0.4
+    vars.push(model.reg_var(0.1));
+    vars.push(model.reg_var(0.2));
+    model.reg_constr(
+        vec![Summand(0.3, &vars[0]), Summand(0.1, &vars[1])],
+        Operator::Le,
+        0.7,
+    );
+    model.reg_constr(
+        vec![Summand(0.1, &vars[0]), Summand(0.3, &vars[1])],
+        Operator::Le,
+        0.5,
+    );
+    model.set_options(SolverOptions {
+        tolerances: Tolerances {
+            primal_feasibility: 1e-7,
+            ..Tolerances::default()
+        },
+        ..SolverOptions::default()
+    });
+    model.optimize();
+    // Test
+    assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+    assert!((model.x(&vars[0]).unwrap() - 2.0).abs() < 1e-9);
+    assert!((model.x(&vars[1]).unwrap() - 1.0).abs() < 1e-9);
+    assert!((model.optimum().unwrap() - 0.4).abs() < 1e-9);
+}
+
+#[test]
+fn revised_backend() {
+    let options = SolverOptions {
+        backend: Backend::Revised,
+        ..SolverOptions::default()
+    };
+    let mut model = Model::new("Test-model (revised, branch-and-bound)", Objective::Max);
+    let mut vars: Vec<Var> = vec![];
+    // x* = (x1,x2) = (2, 4), LP relaxation: (2.25, 3.75)
+    // opt: 26
+    vars.push(model.reg_var(5.0));
+    vars.push(model.reg_var(4.0));
+    model.set_var_type(&vars[0], VarType::Integer);
+    model.set_var_type(&vars[1], VarType::Integer);
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Le,
+        6.0,
+    );
+    model.reg_constr(
+        vec![Summand(10.0, &vars[0]), Summand(6.0, &vars[1])],
+        Operator::Le,
+        45.0,
+    );
+    model.optimize_with(options.clone());
+    // Test
+    assert!((model.x(&vars[0]).unwrap() - 2.0).abs() < 1e-9);
+    assert!((model.x(&vars[1]).unwrap() - 4.0).abs() < 1e-9);
+    assert!((model.optimum().unwrap() - 26.0).abs() < 1e-9);
+    let mut model = Model::new("Test-model (revised, two phases)", Objective::Min);
+    let mut vars: Vec<Var> = vec![];
+    // x* = (x1,x2) = (2/3, 1/3)
+    // opt: 5
+    vars.push(model.reg_var(6.0));
+    vars.push(model.reg_var(3.0));
+    model.reg_constr(
+        vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+        Operator::Ge,
+        1.0,
+    );
+    model.reg_constr(
+        vec![Summand(2.0, &vars[0]), Summand(-1.0, &vars[1])],
+        Operator::Ge,
+        1.0,
+    );
+    model.reg_constr(
+        vec![Summand(0.0, &vars[0]), Summand(3.0, &vars[1])],
+        Operator::Le,
+        2.0,
+    );
+    model.optimize_with(options);
+    // Test
+    assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+    assert!((model.x(&vars[0]).unwrap() - 2.0 / 3.0).abs() < 1e-9);
+    assert!((model.x(&vars[1]).unwrap() - 1.0 / 3.0).abs() < 1e-9);
+    assert!((model.optimum().unwrap() - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn dual_values() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        };
+        let mut model = Model::new("Readme example", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        let mut constrs: Vec<Constr> = vec![];
+        // y* = (y1,y2,y3) = (2, 1, 0)
+        vars.push(model.reg_var(3.0));
+        vars.push(model.reg_var(5.0));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(2.0, &vars[1])],
+            Operator::Le,
+            170.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::Le,
+            150.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(0.0, &vars[0]), Summand(3.0, &vars[1])],
+            Operator::Le,
+            180.0,
+        ));
+        assert!(model.dual(&constrs[0]).is_err());
+        model.optimize_with(options.clone());
+        // Test
+        assert!((model.dual(&constrs[0]).unwrap() - 2.0).abs() < 1e-9);
+        assert!((model.dual(&constrs[1]).unwrap() - 1.0).abs() < 1e-9);
+        assert!(model.dual(&constrs[2]).unwrap().abs() < 1e-9);
+
+        let mut model = Model::new("Test-model (duals, min)", Objective::Min);
+        let mut vars: Vec<Var> = vec![];
+        let mut constrs: Vec<Constr> = vec![];
+        // y* = (y1,y2,y3) = (4, 1, 0)
+        vars.push(model.reg_var(6.0));
+        vars.push(model.reg_var(3.0));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::Ge,
+            1.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(2.0, &vars[0]), Summand(-1.0, &vars[1])],
+            Operator::Ge,
+            1.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(0.0, &vars[0]), Summand(3.0, &vars[1])],
+            Operator::Le,
+            2.0,
+        ));
+        model.optimize_with(options.clone());
+        // Test
+        assert!((model.dual(&constrs[0]).unwrap() - 4.0).abs() < 1e-9);
+        assert!((model.dual(&constrs[1]).unwrap() - 1.0).abs() < 1e-9);
+        assert!(model.dual(&constrs[2]).unwrap().abs() < 1e-9);
+
+        let mut model = Model::new("Test-model (duals, equality)", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        let mut constrs: Vec<Constr> = vec![];
+        // x* = (x1,x2) = (2, 0)
+        // y* = (y1,y2) = (-1, 0)
+        vars.push(model.reg_var(-1.0));
+        vars.push(model.reg_var(-2.0));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::E,
+            2.0,
+        ));
+        constrs.push(model.reg_constr(vec![Summand(1.0, &vars[0])], Operator::Le, 3.0));
+        model.optimize_with(options);
+        // Test
+        assert!((model.optimum().unwrap() + 2.0).abs() < 1e-9);
+        assert!((model.dual(&constrs[0]).unwrap() + 1.0).abs() < 1e-9);
+        assert!(model.dual(&constrs[1]).unwrap().abs() < 1e-9);
+    }
+}
+
+#[test]
+fn reduced_costs() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        };
+        let mut model = Model::new("Test-model (reduced costs, max)", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        // x* = (x1,x2,x3) = (130, 20, 0), y* = (2, 1, 0)
+        // d* = (0, 0, 1 - 2 - 1)
+        vars.push(model.reg_var(3.0));
+        vars.push(model.reg_var(5.0));
+        vars.push(model.reg_var(1.0));
+        model.reg_constr(
+            vec![
+                Summand(1.0, &vars[0]),
+                Summand(2.0, &vars[1]),
+                Summand(1.0, &vars[2]),
+            ],
+            Operator::Le,
+            170.0,
+        );
+        model.reg_constr(
+            vec![
+                Summand(1.0, &vars[0]),
+                Summand(1.0, &vars[1]),
+                Summand(1.0, &vars[2]),
+            ],
+            Operator::Le,
+            150.0,
+        );
+        model.reg_constr(vec![Summand(3.0, &vars[1])], Operator::Le, 180.0);
+        assert!(model.reduced_cost(&vars[0]).is_err());
+        model.optimize_with(options.clone());
+        // Test
+        assert!(model.reduced_cost(&vars[0]).unwrap().abs() < 1e-9);
+        assert!(model.reduced_cost(&vars[1]).unwrap().abs() < 1e-9);
+        assert!((model.reduced_cost(&vars[2]).unwrap() + 2.0).abs() < 1e-9);
+
+        let mut model = Model::new("Test-model (reduced costs, min)", Objective::Min);
+        let mut vars: Vec<Var> = vec![];
+        // x* = (x1,x2,x3) = (2/3, 1/3, 0), y* = (4, 1, 0)
+        // d* = (0, 0, 7 - 4 - 1)
+        vars.push(model.reg_var(6.0));
+        vars.push(model.reg_var(3.0));
+        vars.push(model.reg_var(7.0));
+        model.reg_constr(
+            vec![
+                Summand(1.0, &vars[0]),
+                Summand(1.0, &vars[1]),
+                Summand(1.0, &vars[2]),
+            ],
+            Operator::Ge,
+            1.0,
+        );
+        model.reg_constr(
+            vec![
+                Summand(2.0, &vars[0]),
+                Summand(-1.0, &vars[1]),
+                Summand(1.0, &vars[2]),
+            ],
+            Operator::Ge,
+            1.0,
+        );
+        model.reg_constr(vec![Summand(3.0, &vars[1])], Operator::Le, 2.0);
+        model.optimize_with(options);
+        // Test
+        assert!((model.optimum().unwrap() - 5.0).abs() < 1e-9);
+        assert!(model.reduced_cost(&vars[0]).unwrap().abs() < 1e-9);
+        assert!(model.reduced_cost(&vars[1]).unwrap().abs() < 1e-9);
+        assert!((model.reduced_cost(&vars[2]).unwrap() - 2.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn sensitivity() {
+    fn assert_range(expected: (f64, f64), range: (f64, f64)) {
+        assert!(expected.0 == range.0 || (expected.0 - range.0).abs() < 1e-9);
+        assert!(expected.1 == range.1 || (expected.1 - range.1).abs() < 1e-9);
+    }
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            sensitivity: true,
+            ..SolverOptions::default()
+        };
+        let mut model = Model::new("Readme example", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        let mut constrs: Vec<Constr> = vec![];
+        vars.push(model.reg_var(3.0));
+        vars.push(model.reg_var(5.0));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(2.0, &vars[1])],
+            Operator::Le,
+            170.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::Le,
+            150.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(0.0, &vars[0]), Summand(3.0, &vars[1])],
+            Operator::Le,
+            180.0,
+        ));
+        model.optimize_with(options.clone());
+        // Test
+        assert_range((2.5, 5.0), model.cost_range(&vars[0]).unwrap());
+        assert_range((3.0, 6.0), model.cost_range(&vars[1]).unwrap());
+        assert_range((150.0, 210.0), model.rhs_range(&constrs[0]).unwrap());
+        assert_range((110.0, 170.0), model.rhs_range(&constrs[1]).unwrap());
+        assert_range((60.0, 1.0 / 0.0), model.rhs_range(&constrs[2]).unwrap());
+
+        let mut model = Model::new("Test-model (sensitivity, min)", Objective::Min);
+        let mut vars: Vec<Var> = vec![];
+        let mut constrs: Vec<Constr> = vec![];
+        vars.push(model.reg_var(6.0));
+        vars.push(model.reg_var(3.0));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::Ge,
+            1.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(2.0, &vars[0]), Summand(-1.0, &vars[1])],
+            Operator::Ge,
+            1.0,
+        ));
+        constrs.push(model.reg_constr(
+            vec![Summand(0.0, &vars[0]), Summand(3.0, &vars[1])],
+            Operator::Le,
+            2.0,
+        ));
+        model.optimize_with(options.clone());
+        // Test
+        assert_range((3.0, 1.0 / 0.0), model.cost_range(&vars[0]).unwrap());
+        assert_range((0.5, 1.5), model.rhs_range(&constrs[0]).unwrap());
+
+        let mut model = Model::new("Test-model (sensitivity, equality)", Objective::Max);
+        let mut vars: Vec<Var> = vec![];
+        let mut constrs: Vec<Constr> = vec![];
+        vars.push(model.reg_var(-1.0));
+        vars.push(model.reg_var(-2.0));
+        constrs.push(model.reg_constr(
+            vec![Summand(1.0, &vars[0]), Summand(1.0, &vars[1])],
+            Operator::E,
+            2.0,
+        ));
+        constrs.push(model.reg_constr(vec![Summand(1.0, &vars[0])], Operator::Le, 3.0));
+        model.optimize_with(options);
+        // Test
+        assert_range((-2.0, 1.0 / 0.0), model.cost_range(&vars[0]).unwrap());
+        assert_range((-1.0 / 0.0, -1.0), model.cost_range(&vars[1]).unwrap());
+        assert_range((0.0, 3.0), model.rhs_range(&constrs[0]).unwrap());
+        assert_range((2.0, 1.0 / 0.0), model.rhs_range(&constrs[1]).unwrap());
+    }
+    let mut model = Model::new("Test-model (no sensitivity)", Objective::Max);
+    let var = model.reg_var(1.0);
+    let constr = model.reg_constr(vec![Summand(1.0, &var)], Operator::Le, 1.0);
+    model.optimize();
+    assert!(model.cost_range(&var).is_err());
+    assert!(model.rhs_range(&constr).is_err());
+}
+
+#[test]
+fn variable_bounds() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            sensitivity: true,
+            ..SolverOptions::default()
+        };
+        let mut model = Model::new("Test-model (bounds)", Objective::Max);
+        // x* = (x1,x2) = (3, 2), x1 at its upper bound
+        // opt: 8
+        let x = model.reg_var_bounded(2.0, 0.0, 3.0);
+        let y = model.reg_var_bounded(1.0, 1.0, 4.0);
+        let constr = model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &y)], Operator::Le, 5.0);
+        model.optimize_with(options.clone());
+        // Test
+        assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+        assert!((model.optimum().unwrap() - 8.0).abs() < 1e-9);
+        assert!((model.x(&x).unwrap() - 3.0).abs() < 1e-9);
+        assert!((model.x(&y).unwrap() - 2.0).abs() < 1e-9);
+        assert!((model.dual(&constr).unwrap() - 1.0).abs() < 1e-9);
+        assert!((model.reduced_cost(&x).unwrap() - 1.0).abs() < 1e-9);
+        let (lower, upper) = model.cost_range(&x).unwrap();
+        assert!((lower - 1.0).abs() < 1e-9);
+        assert_eq!(1.0 / 0.0, upper);
+        // y stays within [1, 4]
+        let (lower, upper) = model.rhs_range(&constr).unwrap();
+        assert!((lower - 4.0).abs() < 1e-9);
+        assert!((upper - 7.0).abs() < 1e-9);
+
+        let mut model = Model::new("Test-model (free variable)", Objective::Min);
+        // x* = (x1,x2) = (-5, 0)
+        // opt: -5
+        let x = model.reg_var_bounded(1.0, -1.0 / 0.0, 1.0 / 0.0);
+        let y = model.reg_var(1.0);
+        model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &y)], Operator::Ge, -5.0);
+        model.optimize_with(options.clone());
+        // Test
+        assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+        assert!((model.optimum().unwrap() + 5.0).abs() < 1e-9);
+        assert!((model.x(&x).unwrap() + 5.0).abs() < 1e-9);
+        assert_eq!(0.0, model.x(&y).unwrap());
+
+        let mut model = Model::new("Test-model (free variable, unbounded)", Objective::Max);
+        let x = model.reg_var_bounded(1.0, -1.0 / 0.0, 1.0 / 0.0);
+        let y = model.reg_var(0.0);
+        model.reg_constr(vec![Summand(-1.0, &x), Summand(1.0, &y)], Operator::Le, 1.0);
+        model.optimize_with(options.clone());
+        assert_eq!(SolveStatus::Unbounded, model.status().unwrap());
+
+        let mut model = Model::new("Test-model (lower bounds, infeasible)", Objective::Max);
+        let x = model.reg_var_bounded(1.0, 2.0, 1.0 / 0.0);
+        let y = model.reg_var(1.0);
+        model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &y)], Operator::Le, 1.0);
+        model.optimize_with(options.clone());
+        assert_eq!(SolveStatus::Infeasible, model.status().unwrap());
+
+        let mut model = Model::new("Test-model (crossing bounds)", Objective::Max);
+        let x = model.reg_var_bounded(1.0, 2.0, 1.0);
+        model.reg_constr(vec![Summand(1.0, &x)], Operator::Le, 5.0);
+        model.optimize_with(options);
+        assert_eq!(SolveStatus::Infeasible, model.status().unwrap());
+    }
+}
+
+#[test]
+fn solve_integer_bounds() {
+    let mut model = Model::new("Test-model (integer bounds)", Objective::Min);
+    // x* = (x1,x2) = (-2, 0.5)
+    // opt: -1.5
+    let x = model.reg_var_bounded(1.0, -2.5, 1.0 / 0.0);
+    let y = model.reg_var(1.0);
+    model.set_var_type(&x, VarType::Integer);
+    model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &y)], Operator::Ge, -1.5);
+    model.optimize();
+    // Test
+    assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+    assert_eq!(-2.0, model.x(&x).unwrap());
+    assert!((model.x(&y).unwrap() - 0.5).abs() < 1e-9);
+    assert!((model.optimum().unwrap() + 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn modify_and_warm_start() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let mut model = Model::new("Readme example (modified)", Objective::Max);
+        model.set_options(SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        });
+        let x = model.reg_var(3.0);
+        let y = model.reg_var(5.0);
+        let c_0 = model.reg_constr(
+            vec![Summand(1.0, &x), Summand(2.0, &y)],
+            Operator::Le,
+            170.0,
+        );
+        model.reg_constr(
+            vec![Summand(1.0, &x), Summand(1.0, &y)],
+            Operator::Le,
+            150.0,
+        );
+        model.reg_constr(vec![Summand(3.0, &y)], Operator::Le, 180.0);
+        model.optimize();
+        assert_eq!(490.0, model.optimum().unwrap());
+        assert_eq!(3, model.iteration_count());
+        // x* = (x1,x2) = (50, 60), one pivot from the last basis
+        // opt: 400
+        model.set_objective_value(&x, 2.0);
+        assert!(model.optimum().is_err());
+        model.optimize();
+        assert!((model.optimum().unwrap() - 400.0).abs() < 1e-9);
+        assert!((model.x(&x).unwrap() - 50.0).abs() < 1e-9);
+        assert_eq!(1, model.iteration_count());
+        // The basis stays optimal
+        // opt: 460
+        model.set_rhs(&c_0, 200.0);
+        model.optimize();
+        assert!((model.optimum().unwrap() - 460.0).abs() < 1e-9);
+        assert!((model.x(&x).unwrap() - 80.0).abs() < 1e-9);
+        assert_eq!(0, model.iteration_count());
+        // A cut off basis, repaired by the dual simplex algorithm
+        // opt: 400
+        let c_2 = model.reg_constr(vec![Summand(1.0, &x)], Operator::Le, 50.0);
+        model.optimize();
+        assert!((model.optimum().unwrap() - 400.0).abs() < 1e-9);
+        assert_eq!(1, model.iteration_count());
+        model.remove_constr(&c_2);
+        model.optimize();
+        assert!((model.optimum().unwrap() - 460.0).abs() < 1e-9);
+        // An equality row, its dual value in one piece
+        let c_3 = model.reg_constr(vec![Summand(1.0, &x)], Operator::E, 50.0);
+        model.optimize();
+        assert!((model.optimum().unwrap() - 400.0).abs() < 1e-9);
+        assert!((model.x(&x).unwrap() - 50.0).abs() < 1e-9);
+        assert!((model.dual(&c_3).unwrap() - 2.0).abs() < 1e-9);
+        assert_eq!(1, model.iteration_count());
+        model.remove_constr(&c_3);
+        // A new variable entering the basis
+        // x* = (x1,x2,x3) = (0, 60, 100)
+        // opt: 600
+        let z = model.reg_var(3.0);
+        model.reg_constr(
+            vec![Summand(1.0, &x), Summand(1.0, &z)],
+            Operator::Le,
+            120.0,
+        );
+        model.set_var_bounds(&x, 0.0, 0.0);
+        model.reg_constr(
+            vec![Summand(1.0, &y), Summand(1.0, &z)],
+            Operator::Le,
+            160.0,
+        );
+        model.optimize();
+        assert!((model.optimum().unwrap() - 600.0).abs() < 1e-9);
+        assert!((model.x(&z).unwrap() - 100.0).abs() < 1e-9);
+        model.remove_var(&z);
+        model.set_var_bounds(&x, 0.0, 1.0 / 0.0);
+        model.optimize();
+        assert!((model.optimum().unwrap() - 460.0).abs() < 1e-9);
+        assert!((model.x(&x).unwrap() - 80.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn big_m() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        for &initialization in &[Initialization::TwoPhases, Initialization::BigM(1e6)] {
+            let mut model = Model::new("Test-model (Big-M)", Objective::Min);
+            // x* = (x1,x2) = (3, 1)
+            // opt: 9
+            let x = model.reg_var(2.0);
+            let y = model.reg_var(3.0);
+            model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &y)], Operator::Ge, 4.0);
+            model.reg_constr(vec![Summand(1.0, &x), Summand(3.0, &y)], Operator::Ge, 6.0);
+            model.optimize_with(SolverOptions {
+                backend,
+                initialization,
+                ..SolverOptions::default()
+            });
+            // Test
+            assert_eq!(SolveStatus::Optimal, model.status().unwrap());
+            assert!((model.optimum().unwrap() - 9.0).abs() < 1e-9);
+            assert!((model.x(&x).unwrap() - 3.0).abs() < 1e-9);
+            assert!((model.x(&y).unwrap() - 1.0).abs() < 1e-9);
+            assert!(model.warnings().is_empty());
+        }
+        let mut model = Model::new("Test-model (small M)", Objective::Max);
+        let x = model.reg_var(5.0);
+        model.reg_constr(vec![Summand(1.0, &x)], Operator::Ge, 1.0);
+        model.reg_constr(vec![Summand(1.0, &x)], Operator::Le, 2.0);
+        model.optimize_with(SolverOptions {
+            backend,
+            initialization: Initialization::BigM(1.0),
+            ..SolverOptions::default()
+        });
+        assert_eq!(&[BIG_M_WARNING], model.warnings());
+        // Choosing Big-M after a solve does not warm start from the last basis
+        model.optimize_with(SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        });
+        assert!(model.warnings().is_empty());
+        model.optimize_with(SolverOptions {
+            backend,
+            initialization: Initialization::BigM(1.0),
+            ..SolverOptions::default()
+        });
+        assert!(model.iteration_count() > 0);
+        assert_eq!(&[BIG_M_WARNING], model.warnings());
+        // Infeasible, though unbounded while the artificial variable is positive
+        let mut model = Model::new("Test-model (Big-M, infeasible)", Objective::Max);
+        model.reg_var(1.0);
+        let y = model.reg_var(0.0);
+        model.add_constr(y, Operator::E, -1.0);
+        model.optimize_with(SolverOptions {
+            backend,
+            initialization: Initialization::BigM(1e9),
+            ..SolverOptions::default()
+        });
+        assert_eq!(SolveStatus::Infeasible, model.status().unwrap());
+        assert!(model.unbounded_ray().is_err());
+    }
+}
+
+#[test]
+fn iis() {
+    let mut model = Model::new("Test-model (IIS)", Objective::Max);
+    let x = model.reg_var(1.0);
+    let y = model.reg_var(1.0);
+    let z = model.reg_var_bounded(1.0, 0.0, 1.0);
+    let c_0 = model.reg_constr(vec![Summand(1.0, &x), Summand(1.0, &y)], Operator::Le, 2.0);
+    assert!(model.iis().is_err());
+    let c_1 = model.reg_constr(vec![Summand(1.0, &x)], Operator::Ge, 3.0);
+    model.reg_constr(vec![Summand(1.0, &y)], Operator::Le, 10.0);
+    model.reg_constr(vec![Summand(1.0, &z)], Operator::E, 1.0);
+    assert_eq!(SolveStatus::Infeasible, model.solve());
+    // x + y <= 2, x >= 3 and y >= 0
+    let iis = model.iis().unwrap();
+    assert_eq!(vec![c_0, c_1], iis.constrs);
+    assert_eq!(vec![y], iis.lower_bounds);
+    assert!(iis.upper_bounds.is_empty());
+
+    let mut model = Model::new("Test-model (IIS, bounds)", Objective::Max);
+    let x = model.reg_var_bounded(1.0, 0.0, 1.0);
+    let constr = model.reg_constr(vec![Summand(1.0, &x)], Operator::Ge, 2.0);
+    let iis = model.iis().unwrap();
+    assert_eq!(vec![constr], iis.constrs);
+    assert!(iis.lower_bounds.is_empty());
+    assert_eq!(vec![x], iis.upper_bounds);
+}
+
+#[test]
+fn certificates() {
+    for &backend in &[Backend::Tableau, Backend::Revised] {
+        let options = SolverOptions {
+            backend,
+            ..SolverOptions::default()
+        };
+        let mut model = Model::new("Test-model (Farkas)", Objective::Max);
+        let x = model.reg_var(1.0);
+        let c_0 = model.reg_constr(vec![Summand(1.0, &x)], Operator::Le, 1.0);
+        let c_1 = model.reg_constr(vec![Summand(1.0, &x)], Operator::Ge, 2.0);
+        assert_eq!(
+            Result::Err(LinprogError::NotOptimized),
+            model.farkas_certificate()
+        );
+        model.optimize_with(options.clone());
+        // Test
+        // 1 * (x <= 1) - 1 * (x >= 2) gives 0 <= -1
+        assert_eq!(SolveStatus::Infeasible, model.status().unwrap());
+        assert_eq!(
+            vec![(c_0, 1.0), (c_1, -1.0)],
+            model.farkas_certificate().unwrap()
+        );
+        assert!(model.unbounded_ray().is_err());
+        // The root relaxation of an integer model
+        model.set_var_type(&x, VarType::Integer);
+        model.optimize();
+        assert_eq!(SolveStatus::Infeasible, model.status().unwrap());
+        assert_eq!(2, model.farkas_certificate().unwrap().len());
+
+        let mut model = Model::new("Test-model (ray)", Objective::Min);
+        let x = model.reg_var(-1.0);
+        let y = model.reg_var(-1.0);
+        model.reg_constr(vec![Summand(1.0, &x), Summand(-1.0, &y)], Operator::Le, 1.0);
+        model.reg_constr(vec![Summand(1.0, &x)], Operator::Ge, 1.0);
+        model.optimize_with(options);
+        // Test
+        assert_eq!(SolveStatus::Unbounded, model.status().unwrap());
+        assert!(model.farkas_certificate().is_err());
+        let ray = model.unbounded_ray().unwrap();
+        assert_eq!((&x, &y), (&ray[0].0, &ray[1].0));
+        let (r_x, r_y) = (ray[0].1, ray[1].1);
+        // x - y does not grow, x does not shrink, the objective decreases
+        assert!(r_x - r_y <= 1e-9);
+        assert!(r_x >= -1e-9 && r_y >= -1e-9);
+        assert!(-r_x - r_y < -1e-9);
+    }
+}
+
+/// The MPS example with the fields at their columns, and an integer variable
+fn mps_example() -> &'static str {
+    "NAME          TESTPROB
+* The objective is minimized
+ROWS
+ N  COST
+ L  LIM1
+ G  LIM2
+ E  MYEQN
+COLUMNS
+    XONE      COST               1.0   LIM1               1.0
+    XONE      LIM2               1.0
+    MARKER                 'MARKER'                 'INTORG'
+    YTWO      COST               2.0   LIM1               1.0
+    YTWO      MYEQN             -1.0
+    MARKER                 'MARKER'                 'INTEND'
+    Z THREE   COST              -1.0   LIM2               1.0
+    Z THREE   MYEQN              1.0
+RHS
+    RHS       LIM1               4.0   LIM2               1.0
+    RHS       MYEQN              7.0
+RANGES
+    RNG       LIM1               2.5
+BOUNDS
+ UP BND       XONE               4.0
+ LO BND       YTWO              -1.0
+ UP BND       YTWO               1.0
+ENDATA
+"
+}
+
+#[test]
+fn mps() {
+    // x* = (x1,x2,x3) = (2.5, -1, 6), x1 + x2 >= 1.5 by the range
+    // opt: -5.5
+    let mut model = Model::from_mps(mps_example(), MpsFormat::Fixed).unwrap();
+    assert_eq!(SolveStatus::Optimal, model.solve());
+    assert!((model.optimum().unwrap() + 5.5).abs() < 1e-9);
+    // Names with spaces only fit the fixed format
+    assert!(model.write_mps(MpsFormat::Free).is_err());
+    let fixed = model.write_mps(MpsFormat::Fixed).unwrap();
+    let mut read = Model::from_mps(&fixed, MpsFormat::Fixed).unwrap();
+    assert_eq!(fixed, read.write_mps(MpsFormat::Fixed).unwrap());
+    assert_eq!(SolveStatus::Optimal, read.solve());
+    assert!((read.optimum().unwrap() + 5.5).abs() < 1e-9);
+
+    let mut model = Model::new("Readme example", Objective::Max);
+    let x = model.reg_var_with_name(3.0, "x");
+    let y = model.reg_var_bounded(5.0, -1.0 / 0.0, 70.0);
+    model.set_var_type(&y, VarType::Integer);
+    model.reg_constr(
+        vec![Summand(1.0, &x), Summand(2.0, &y)],
+        Operator::Le,
+        170.0,
+    );
+    model.reg_constr(
+        vec![Summand(1.0, &x), Summand(1.0, &y)],
+        Operator::Le,
+        150.0,
+    );
+    model.reg_constr(vec![Summand(3.0, &y)], Operator::Ge, -180.0);
+    model.reg_constr(vec![Summand(1.0, &x), Summand(-1.0, &y)], Operator::E, 0.1);
+    let free = model.write_mps(MpsFormat::Free).unwrap();
+    assert!(free.contains(" MI BND C2\n UP BND C2 70.0\n"));
+    let mut read = Model::from_mps(&free, MpsFormat::Free).unwrap();
+    assert_eq!(free, read.write_mps(MpsFormat::Free).unwrap());
+    read.optimize();
+    model.optimize();
+    assert!((read.optimum().unwrap() - model.optimum().unwrap()).abs() < 1e-9);
+}
+
+#[test]
+fn mps_errors() {
+    let unknown_row = mps_example().replace("LIM2               1.0\n", "LIM3               1.0\n");
+    assert_eq!(
+        Result::Err(ParseError {
+            line: 10,
+            column: 15,
+            message: "Unknown row",
+        }),
+        Model::from_mps(&unknown_row, MpsFormat::Fixed).map(|_| ())
+    );
+    let invalid_number = mps_example().replace("-1.0\n    MARKER", "-1,0\n    MARKER");
+    let error = Model::from_mps(&invalid_number, MpsFormat::Fixed)
+        .err()
+        .unwrap();
+    assert_eq!(
+        (13, 25, "Invalid number"),
+        (error.line, error.column, error.message)
+    );
+    let error = Model::from_mps("ROWS\n N obj\n", MpsFormat::Free)
+        .err()
+        .unwrap();
+    assert_eq!((3, "Missing ENDATA"), (error.line, error.message));
+    // Text only in the gaps between the fixed fields, in every section
+    let sections = [
+        "NAME x\n",
+        "OBJSENSE\n",
+        "ROWS\n N  obj\n L  c\n",
+        "COLUMNS\n    x         c         1.0\n",
+        "RHS\n    RHS       c         1.0\n",
+        "RANGES\n    RNG       c         1.0\n",
+        "BOUNDS\n UP BND       x         1.0\n",
+    ];
+    for section in 0..sections.len() {
+        let mut mps: String = sections[..=section].concat();
+        let line = mps.lines().count() + 1;
+        mps.push_str("   X\n");
+        mps.push_str(&sections[section + 1..].concat());
+        mps.push_str("ENDATA\n");
+        assert_eq!(
+            Result::Err(ParseError {
+                line,
+                column: 4,
+                message: "Text outside of the fields",
+            }),
+            Model::from_mps(&mps, MpsFormat::Fixed).map(|_| ())
+        );
+    }
+}
+
+#[test]
+fn lp_format() {
+    let lp = "\\Problem name: Readme example
+Maximize
+ obj: 3 x + 5 y
+Subject To
+ c1: x + 2 y <= 170
+ c2: x + y <= 150
+ c3: 3 y >= -180
+ c4: 0 <= x - y <= 0.5
+Bounds
+ -inf <= y <= 70
+Generals
+ y
+End
+";
+    let mut model = Model::from_lp(lp).unwrap();
+    assert_eq!(SolveStatus::Optimal, model.solve());
+    // x* = (x,y) = (56.5, 56), opt: 449.5
+    assert!((model.optimum().unwrap() - 449.5).abs() < 1e-9);
+    let written = model.write_lp().unwrap();
+    assert_eq!(
+        "\\Problem name: Readme example
+
+Maximize
+ obj: 3.0 x + 5.0 y
+Subject To
+ c1: x + 2.0 y <= 170.0
+ c2: x + y <= 150.0
+ c3: 3.0 y >= -180.0
+ c4: 0.0 <= x - y <= 0.5
+Bounds
+ -inf <= y <= 70.0
+Generals
+ y
+End
+",
+        written
+    );
+    let mut read = Model::from_lp(&written).unwrap();
+    assert_eq!(written, read.write_lp().unwrap());
+    assert_eq!(SolveStatus::Optimal, read.solve());
+    assert!((read.optimum().unwrap() - 449.5).abs() < 1e-9);
+
+    // Unnamed variables and constraints get names by their position
+    let mut model = Model::new("", Objective::Min);
+    let x = model.reg_var(-1.0);
+    let y = model.reg_var_bounded(0.0, 1.0, 1.0);
+    model.set_var_type(&y, VarType::Binary);
+    model.reg_constr(vec![Summand(1.0, &x), Summand(-2.0, &y)], Operator::E, 0.0);
+    let written = model.write_lp().unwrap();
+    assert!(written.contains(" obj: - C1 + 0.0 C2\n"));
+    assert!(written.contains(" R1: C1 - 2.0 C2 = 0.0\n"));
+    assert!(written.contains("Binaries\n C2\n"));
+    let mut read = Model::from_lp(&written).unwrap();
+    assert_eq!(written, read.write_lp().unwrap());
+    read.optimize();
+    assert_eq!(Result::Ok(-2.0), read.optimum());
+
+    model.reg_var_with_name(1.0, "free");
+    assert_eq!(
+        Result::Err(LinprogError::InvalidName(
+            "Name is a keyword of the LP format"
+        )),
+        model.write_lp()
+    );
+}
+
+#[test]
+fn lp_format_errors() {
+    let error = |lp: &str| {
+        let error = Model::from_lp(lp).err().unwrap();
+        (error.line, error.column, error.message)
+    };
+    assert_eq!(
+        (3, 10, "Expected a comparison"),
+        error("Maximize\n obj: x\nst c1: x 3\nEnd\n")
+    );
+    assert_eq!(
+        (2, 15, "Expected + or -"),
+        error("Minimize\n obj: x + 3 y z\nEnd\n")
+    );
+    assert_eq!(
+        (3, 11, "Invalid number"),
+        error("Minimize\n obj: x\n c1: x >= 1.2.3\nEnd\n")
+    );
+    // Missing tokens are reported where the next section starts
+    assert_eq!(
+        (3, 1, "Expected a variable"),
+        error("Minimize\n obj: x + 3\nEnd\n")
+    );
+    assert_eq!(
+        (4, 13, "Expected two comparisons of the same direction"),
+        error("Minimize\n obj: x\nst\n c1: 1 <= x >= 0\nEnd\n")
+    );
+    assert_eq!((1, 1, "Data outside of a section"), error("x + y\nEnd\n"));
+    assert_eq!((3, 1, "Missing End"), error("Minimize\n obj: x\n"));
+}
+
+#[test]
+fn lin_expr() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    // x* = (x1,x2) = (3.6, 0.4)
+    // opt: 7.6
+    let x = model.reg_var(2.0);
+    let y = model.reg_var(1.0);
+    model.add_constr(2.0 * x - 3.0 * y, Operator::Le, 6.0);
+    let c = model.add_constr(x + y, Operator::Le, 4.0);
+    // Summands still build sums, mixed with expressions
+    model.add_constr(LinExpr::from(Summand(1.0, &x)) - 4.0, Operator::Le, 0.0);
+    model.add_constr(-y, Operator::Ge, -5.0);
+    model.optimize();
+    assert_eq!(Result::Ok(3.6), model.x(&x));
+    assert_eq!(Result::Ok(0.4), model.x(&y));
+    assert_eq!(Result::Ok(7.6), model.optimum());
+    assert!(model.dual(&c).unwrap() > 0.0);
+}
+
+#[test]
+fn lp_macro() {
+    // The model of solve_1
+    // x* = (x1,x2) = (3.6, 0.4)
+    // opt: 7.6
+    let (mut model, vars) = lp! {
+        max 2 x1 + x2;
+        2 x1 - 3 x2 <= 6;
+        x1 + x2 <= 4;
+    };
+    model.optimize();
+    assert_eq!(Result::Ok(3.6), model.x(&vars["x1"]));
+    assert_eq!(Result::Ok(0.4), model.x(&vars["x2"]));
+    assert_eq!(Result::Ok(7.6), model.optimum());
+
+    // Variables and constants on both sides, the objective's constant is ignored
+    // x* = (x,y,z) = (4.5, 1.5, 2)
+    // opt: -0.75
+    let (mut model, vars) = lp! {
+        min - x + 2.5 * y + 1;
+        x + 1 >= 2 y - 3;
+        -y <= -1.5;
+        x + y == 4 + z;
+        z <= 2
+    };
+    assert_eq!(3, vars.len());
+    assert_eq!(SolveStatus::Optimal, model.solve());
+    assert_eq!(Result::Ok(4.5), model.x(&vars["x"]));
+    assert_eq!(Result::Ok(1.5), model.x(&vars["y"]));
+    assert_eq!(Result::Ok(2.0), model.x(&vars["z"]));
+    assert_eq!(Result::Ok(-0.75), model.optimum());
+}
+
+#[test]
+fn names() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    // x* = (x1,x2) = (3.6, 0.4)
+    // opt: 7.6
+    let x = model.reg_var_with_name(2.0, "x");
+    let y = model.reg_var(1.0);
+    model.reg_constr_with_name(
+        vec![Summand(2.0, &x), Summand(-3.0, &y)],
+        Operator::Le,
+        6.0,
+        "ratio",
+    );
+    let capacity = model.add_constr_with_name(x + y, Operator::Le, 4.0, "capacity");
+    // The first registered one is found for a name given twice
+    let unused = model.add_constr_with_name(x, Operator::Le, 10.0, "unused");
+    model.add_constr_with_name(x, Operator::Le, 20.0, "unused");
+    assert_eq!(Option::Some(x), model.var_by_name("x"));
+    assert_eq!(Option::None, model.var_by_name("y"));
+    assert_eq!(Option::Some(capacity), model.constr_by_name("capacity"));
+    assert_eq!(Option::Some(unused), model.constr_by_name("unused"));
+    assert_eq!(Option::None, model.constr_by_name("x"));
+    model.optimize();
+    let capacity = model.constr_by_name("capacity").unwrap();
+    assert_eq!(Result::Ok(3.6), model.x(&model.var_by_name("x").unwrap()));
+    assert!(model.dual(&capacity).unwrap() > 0.0);
+    model.remove_constr(&capacity);
+    assert_eq!(Option::None, model.constr_by_name("capacity"));
+
+    // Models read from files keep the names
+    let model = Model::from_lp("Maximize\n obj: x\nSubject To\n c1: x <= 4\nEnd\n").unwrap();
+    assert!(model.var_by_name("x").is_some());
+    assert!(model.constr_by_name("c1").is_some());
+    let (model, vars) = lp! {
+        max x;
+        x <= 4;
+    };
+    assert_eq!(Option::Some(vars["x"]), model.var_by_name("x"));
+}
+
+#[test]
+fn errors() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let mut other = Model::new("Other-model", Objective::Max);
+    let x = model.reg_var(1.0);
+    let y = other.reg_var(1.0);
+    let c = model.add_constr(x, Operator::Le, 2.0);
+    let d = other.add_constr(y, Operator::Le, 2.0);
+    // Handles are copied as keys, those of different models differ
+    let handles: HashMap<Constr, Var> = vec![(c, x), (d, y)].into_iter().collect();
+    assert_eq!(2, handles.len());
+    assert_eq!(Option::Some(&x), handles.get(&c));
+    assert_ne!(x, y);
+    assert_eq!(Result::Err(LinprogError::NotOptimized), model.x(&x));
+    assert_eq!(Result::Err(LinprogError::NotOptimized), model.status());
+
+    // Handles of other models are errors of the try_ variants, leaving the model unchanged
+    assert_eq!(Result::Err(LinprogError::ForeignVariable), model.try_x(&y));
+    assert_eq!(
+        Result::Err(LinprogError::ForeignVariable),
+        model.try_reduced_cost(&y)
+    );
+    assert_eq!(
+        Result::Err(LinprogError::ForeignConstraint),
+        model.try_dual(&d)
+    );
+    assert_eq!(
+        Option::Some(LinprogError::ForeignVariable),
+        model.try_set_var_bounds(&y, 0.0, 1.0).err()
+    );
+    assert_eq!(
+        Option::Some(LinprogError::ForeignVariable),
+        model.try_add_constr(x + y, Operator::Le, 1.0).err()
+    );
+    assert_eq!(
+        Option::Some(LinprogError::ForeignVariable),
+        model.try_set_objective(2.0 * x + y).err()
+    );
+    assert_eq!(
+        Option::Some(LinprogError::ForeignConstraint),
+        model.try_set_rhs(&d, 1.0).err()
+    );
+    assert!(model.try_set_rhs(&c, 3.0).is_ok());
+    model.optimize();
+    assert_eq!(Result::Ok(3.0), model.optimum());
+    assert!(model.try_remove_var(&x).is_ok());
+    assert_eq!(
+        Option::Some(LinprogError::ForeignVariable),
+        model.try_remove_var(&x).err()
+    );
+    assert_eq!(
+        Option::Some(LinprogError::ForeignConstraint),
+        model.try_remove_constr(&d).err()
+    );
+
+    // A missing solution tells the status
+    let mut model = Model::new("Test-model (infeasible)", Objective::Max);
+    let x = model.reg_var(1.0);
+    model.add_constr(x, Operator::Le, 1.0);
+    model.add_constr(x, Operator::Ge, 2.0);
+    model.optimize();
+    assert_eq!(Result::Err(LinprogError::Infeasible), model.x(&x));
+    assert_eq!(
+        Result::Err(LinprogError::NotAvailable("ray")),
+        model.unbounded_ray()
+    );
+    let mut model = Model::new("Test-model (unbounded)", Objective::Max);
+    let x = model.reg_var(1.0);
+    model.optimize();
+    assert_eq!(Result::Err(LinprogError::Unbounded), model.x(&x));
+    assert_eq!(
+        Result::Err(LinprogError::NotInfeasible),
+        model.iis().map(|_| ())
+    );
+
+    // Errors work with ?
+    let read = || -> Result<f64, Box<dyn std::error::Error>> {
+        let mut model = Model::from_lp("Maximize\n obj: x\nSubject To\n x <= 4\nEnd\n")?;
+        model.optimize();
+        Result::Ok(model.optimum()?)
+    };
+    assert_eq!(4.0, read().unwrap());
+    let error = Model::from_lp("Maximize\n obj: x\n")
+        .map_err(LinprogError::from)
+        .err()
+        .unwrap();
+    assert!(matches!(error, LinprogError::Parse(_)));
+    assert!(error.to_string().ends_with("Missing End"));
+    assert_eq!("Model is unbounded", LinprogError::Unbounded.to_string());
+}
+
+#[test]
+fn solution() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    // x* = (x1,x2) = (3.6, 0.4)
+    // opt: 7.6
+    let x = model.reg_var(2.0);
+    let y = model.reg_var(1.0);
+    model.add_constr(2.0 * x - 3.0 * y, Operator::Le, 6.0);
+    let capacity = model.add_constr(x + y, Operator::Le, 4.0);
+    assert_eq!(Result::Err(LinprogError::NotOptimized), model.solution());
+    assert_eq!(vec![x, y], model.vars());
+    assert_eq!(capacity, model.constrs()[1]);
+    model.optimize();
+    let solution = model.solution().unwrap();
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(Option::Some(7.6), solution.optimum);
+    assert_eq!(Option::Some(vec![3.6, 0.4]), solution.x);
+    assert_eq!(model.dual(&capacity).unwrap(), solution.duals.unwrap()[1]);
+
+    // Integer models have no dual values
+    model.set_var_type(&x, VarType::Integer);
+    model.optimize();
+    let solution = model.solution().unwrap();
+    assert_eq!(Result::Ok(7.0), model.optimum());
+    assert_eq!(2, solution.x.unwrap().len());
+    assert_eq!(Option::None, solution.duals);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let mut model = Model::new("Test-model", Objective::Max);
+    let x = model.reg_var_with_name(2.0, "x");
+    let y = model.reg_var(1.0);
+    let removed = model.reg_var(5.0);
+    model.set_var_bounds(&y, 0.0, 10.0);
+    model.add_constr_with_name(2.0 * x - 3.0 * y, Operator::Le, 6.0, "ratio");
+    model.add_constr(x + y + removed, Operator::Le, 4.0);
+    model.remove_var(&removed);
+    model.optimize();
+
+    // The reloaded model is not optimized, its handles are its own
+    let json = serde_json::to_string(&model).unwrap();
+    let mut read: Model = serde_json::from_str(&json).unwrap();
+    assert_eq!(Result::Err(LinprogError::NotOptimized), read.status());
+    assert_eq!(Result::Err(LinprogError::ForeignVariable), read.try_x(&x));
+    let vars = read.vars();
+    assert_eq!(2, vars.len());
+    assert_eq!(Option::Some(vars[0]), read.var_by_name("x"));
+    assert!(read.constr_by_name("ratio").is_some());
+    read.optimize();
+    assert_eq!(model.solution(), read.solution());
+    read.add_constr(vars[1], Operator::Ge, 1.0);
+    read.optimize();
+    assert_eq!(Result::Ok(7.0), read.optimum());
+
+    let bytes = bincode::serialize(&model).unwrap();
+    let mut read: Model = bincode::deserialize(&bytes).unwrap();
+    read.optimize();
+    assert_eq!(model.solution(), read.solution());
+
+    let solution = model.solution().unwrap();
+    let json = serde_json::to_string(&solution).unwrap();
+    assert_eq!(solution, serde_json::from_str(&json).unwrap());
+    // Infinite values are written as strings in JSON
+    let mut model = Model::new("Test-model (infeasible)", Objective::Max);
+    let x = model.reg_var_bounded(1.0, f64::NEG_INFINITY, 1.0);
+    model.add_constr(x, Operator::Ge, 2.0);
+    model.optimize();
+    let solution = model.solution().unwrap();
+    let json = serde_json::to_string(&solution).unwrap();
+    assert!(json.contains("\"-inf\""));
+    assert_eq!(solution, serde_json::from_str(&json).unwrap());
+    let json = serde_json::to_string(&model).unwrap();
+    let mut read: Model = serde_json::from_str(&json).unwrap();
+    assert_eq!(SolveStatus::Infeasible, read.solve());
+    assert!(serde_json::from_str::<Model>(&json.replace("-inf", "minus")).is_err());
+
+    // Hand-edited or truncated descriptions are rejected instead of giving an inconsistent model
+    let mut model = Model::new("Test-model (edited)", Objective::Max);
+    let x = model.reg_var(1.0);
+    model.add_constr(x, Operator::Le, 2.0);
+    let json = serde_json::to_string(&model).unwrap();
+    assert!(json.contains("[[0,1.0]]"));
+    let error = serde_json::from_str::<Model>(&json.replace("[[0,1.0]]", "[[1,1.0]]"))
+        .err()
+        .unwrap();
+    assert!(error
+        .to_string()
+        .contains(&LinprogError::ForeignVariable.to_string()));
+    assert!(
+        serde_json::from_str::<Model>(&json.replace("\"range\":\"inf\"", "\"range\":-1.0"))
+            .is_err()
+    );
+    assert!(serde_json::from_str::<Model>(&json[..json.len() / 2]).is_err());
+    // Ranged constraints keep their range
+    let mut model = Model::from_mps(
+        "NAME ranged\nROWS\n N obj\n G c\nCOLUMNS\n x obj -1 c 1\nRHS\n RHS c 1\nRANGES\n RNG c 2\nENDATA\n",
+        MpsFormat::Free,
+    )
+    .unwrap();
+    let json = serde_json::to_string(&model).unwrap();
+    let mut read: Model = serde_json::from_str(&json).unwrap();
+    model.optimize();
+    read.optimize();
+    assert_eq!(Result::Ok(-3.0), read.optimum());
+    assert_eq!(model.solution(), read.solution());
+}